@@ -60,9 +60,11 @@
             wantedBy = [ "multi-user.target" ];
             after = [ "network.target" ];
             serviceConfig = {
-              ExecStart = "${self.packages.musicbot}/bin/musicbot --yt-dlp=${pkgs.yt-dlp}/bin/yt-dlp --token=${config.services.musicbot.discordTokenPath} ${lib.optionalString (config.services.musicbot.audioDirectory != null) "--local-audio=${config.services.musicbot.audioDirectory}"} ${lib.optionalString (config.services.musicbot.cookiesPath != null) "--cookies=${config.services.musicbot.cookiesPath}"}";
+              ExecStart = "${self.packages.musicbot}/bin/musicbot --yt-dlp=${pkgs.yt-dlp}/bin/yt-dlp --data-directory=/var/lib/musicbot ${lib.optionalString (config.services.musicbot.audioDirectory != null) "--local-audio=${config.services.musicbot.audioDirectory}"} ${lib.optionalString (config.services.musicbot.cookiesPath != null) "--cookies=${config.services.musicbot.cookiesPath}"}";
+              LoadCredential = "discord-token:${config.services.musicbot.discordTokenPath}";
               Restart = "on-failure";
               RestartSec = "5s";
+              StateDirectory = "musicbot";
               StandardOutput = "journal";
               StandardError = "journal";
             };
@@ -0,0 +1,225 @@
+## Messages
+
+language-name = Deutsch
+
+unknown-command = Unbekannter Befehl
+unknown-title = Unbekannter Titel
+artist = Künstler
+author = Autor
+duration = Dauer
+queue-length = Länge der Warteschlange
+queue-position = Position in der Warteschlange
+now-playing = Läuft gerade
+added-to-queue = Zur Warteschlange hinzugefügt
+queue = Warteschlange
+requested-by = Gewünscht von
+failed-to-fetch = Titelinformationen konnten nicht abgerufen werden
+failed-to-join = Sprachkanal konnte nicht betreten werden
+failed-to-play = Titel konnte nicht abgespielt werden
+command-only-in-guild = Dieser Befehl kann nur auf einem Server verwendet werden.
+user-must-be-in-voice-channel = Du musst in einem Sprachkanal sein.
+bot-must-be-in-voice-channel = Der Bot muss in einem Sprachkanal sein.
+queue-empty = Die Warteschlange ist leer.
+failed-to-skip = Titel konnte nicht übersprungen werden.
+skipped = Titel übersprungen.
+shuffled = Warteschlange gemischt.
+disconnected = Verbindung getrennt.
+failed-to-disconnect = Verbindung konnte nicht getrennt werden.
+cleared-queue = Warteschlange geleert.
+failed-to-change-channel = Sprachkanal konnte nicht gewechselt werden.
+changed-channel = Sprachkanal gewechselt.
+no-such-file = Keine solche Datei gefunden.
+statistics = Statistiken
+top-tracks = Meistgespielte Titel
+top-requesters = Aktivste Hörer
+total-listening-time = Gesamte Hördauer
+no-statistics = Im gewählten Zeitraum wurden keine Titel abgespielt.
+stay-enabled = Der Bot bleibt in diesem Sprachkanal.
+stay-disabled = Der Bot bleibt nicht mehr im Sprachkanal.
+queue-restored = Warteschlange nach dem Neustart wiederhergestellt.
+restarting = Der Bot wird neu gestartet, die Warteschlange wird wiederhergestellt.
+skip-vote-registered = Für das Überspringen des Titels abgestimmt ({ $votes }/{ $required } { $required ->
+        [one] Stimme
+       *[other] Stimmen
+    }).
+missing-permissions = Nur ein DJ, ein Serververwalter oder die Person, die diese Titel hinzugefügt hat, kann diesen Befehl verwenden.
+missing-server-permissions = Dir fehlen die Serverberechtigungen, die für diesen Befehl nötig sind.
+settings-updated = Einstellungen aktualisiert.
+limit-out-of-range = Das Limit muss zwischen 0 und { $max } liegen.
+server-settings = Servereinstellungen
+setting-volume = Lautstärke
+setting-language = Sprache
+setting-dj-role = DJ-Rolle
+setting-announcement-channel = Ankündigungskanal
+setting-idle-timeout = Verlassen nach Ende der Warteschlange
+setting-max-queue-length = Maximale Warteschlangenlänge
+setting-vote-skip-threshold = Benötigte Stimmen zum Überspringen
+setting-announce-now-playing = Ankündigung laufender Titel
+setting-max-user-tracks = Titel pro Person
+setting-max-duration = Maximale Titeldauer
+setting-fair-queue = Faire Warteschlange
+setting-refuse-livestreams = Livestreams ablehnen
+setting-refuse-age-restricted = Altersbeschränkte Inhalte ablehnen
+setting-allowed-domains = Erlaubte Domains
+setting-blocked-domains = Gesperrte Domains
+setting-blocked-keywords = Gesperrte Begriffe
+setting-blocked-uploaders = Gesperrte Uploader
+per-user = Pro Person
+not-set = Nicht festgelegt
+command-channel = Kanal des Befehls
+immediately = Sofort
+never = Nie
+unlimited = Unbegrenzt
+on = An
+off = Aus
+invalid-idle-timeout = Die Zeit muss eine Anzahl Minuten, `immediate` oder `never` sein.
+track-too-long = Der Titel ist zu lang, die maximale Dauer beträgt { $max }.
+queue-full = Die Warteschlange ist voll, die maximale Länge beträgt { $max }.
+user-track-limit = Du hast bereits die maximale Anzahl an Titeln in der Warteschlange ({ $max }).
+domain-not-allowed = Wiedergabe von { $domain } ist auf diesem Server nicht erlaubt.
+blocked-keyword = Der Titel enthält einen gesperrten Begriff.
+blocked-uploader = Titel von { $uploader } sind auf diesem Server gesperrt.
+livestream-not-allowed = Livestreams sind auf diesem Server nicht erlaubt.
+age-restricted = Altersbeschränkte Inhalte sind auf diesem Server nicht erlaubt.
+yt-dlp-missing = Das Abrufen von Titeln ist nicht verfügbar, yt-dlp wurde nicht gefunden.
+fetch-timeout = Das Abrufen der Titelinformationen hat zu lange gedauert.
+unsupported-url = Diese URL wird nicht unterstützt.
+video-unavailable = Dieser Titel ist nicht verfügbar.
+geo-blocked = Dieser Titel ist in diesem Land nicht verfügbar.
+login-required = Für diesen Titel ist eine Anmeldung erforderlich.
+private-video = Dieser Titel ist privat.
+age-verification-required = Für diesen Titel ist eine Altersbestätigung erforderlich.
+rate-limited = Der Dienst begrenzt die Anfragen, versuche es später erneut.
+extractor-failed = Die Seite des Titels konnte nicht gelesen werden.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } Titel
+   *[other] { $count } Titel
+}
+plays = { $count ->
+    [one] { $count } Wiedergabe
+   *[other] { $count } Wiedergaben
+}
+duration-hours = { $count ->
+    [one] { $count } Stunde
+   *[other] { $count } Stunden
+}
+duration-minutes = { $count ->
+    [one] { $count } Minute
+   *[other] { $count } Minuten
+}
+duration-seconds = { $count ->
+    [one] { $count } Sekunde
+   *[other] { $count } Sekunden
+}
+relative-hours = { $count ->
+    [one] in { $count } Stunde
+   *[other] in { $count } Stunden
+}
+relative-minutes = { $count ->
+    [one] in { $count } Minute
+   *[other] in { $count } Minuten
+}
+relative-seconds = { $count ->
+    [one] in { $count } Sekunde
+   *[other] in { $count } Sekunden
+}
+starts = Beginnt
+
+## Commands
+
+command-play = abspielen
+    .description = Spielt Musik von einer URL oder einem Suchbegriff ab
+command-play-query = suche
+    .description = Die URL oder der Suchbegriff
+command-skip = überspringen
+    .description = Überspringt den aktuellen Titel
+command-disconnect = trennen
+    .description = Verlässt den Sprachkanal
+command-change_channel = kanal_wechseln
+    .description = Wechselt in den Sprachkanal der anfragenden Person
+command-queue = warteschlange
+    .description = Zeigt die Warteschlange an
+command-clear_queue = warteschlange_leeren
+    .description = Leert die Warteschlange
+command-shuffle = mischen
+    .description = Mischt die Warteschlange
+command-play_local = lokal_abspielen
+    .description = Spielt Inhalte aus der vorbereiteten Liste ab
+command-play_local-query = suche
+    .description = Der Name der abzuspielenden Datei
+command-stats = statistiken
+    .description = Zeigt die Hörstatistiken dieses Servers
+command-stats-period = zeitraum
+    .description = Der Zeitraum der Statistiken
+command-stats-period-week = Letzte Woche
+command-stats-period-month = Letzter Monat
+command-stats-period-all = Gesamter Zeitraum
+command-stay = bleiben
+    .description = Schaltet das dauerhafte Bleiben im Sprachkanal um
+command-settings = einstellungen
+    .description = Ändert die Einstellungen des Bots auf diesem Server
+command-settings-fair_queue = faire_warteschlange
+    .description = Wechselt zwischen anfragenden Personen ab, statt Titel ans Ende zu stellen
+command-settings-fair_queue-enabled = aktiviert
+    .description = Ob die faire Warteschlange aktiviert ist
+command-settings-filter_add = filter_hinzufügen
+    .description = Fügt einer Inhaltsfilterliste einen Eintrag hinzu
+command-settings-filter_remove = filter_entfernen
+    .description = Entfernt einen Eintrag aus einer Inhaltsfilterliste
+command-settings-filter-list = liste
+    .description = Die Filterliste
+command-settings-filter-list-allowed_domains = Erlaubte Domains
+command-settings-filter-list-blocked_domains = Gesperrte Domains
+command-settings-filter-list-blocked_keywords = Gesperrte Begriffe
+command-settings-filter-list-blocked_uploaders = Gesperrte Uploader
+command-settings-filter-value = wert
+    .description = Domain, Begriff oder Uploader
+command-settings-restrictions = einschränkungen
+    .description = Legt fest, ob Livestreams und altersbeschränkte Inhalte abgelehnt werden
+command-settings-restrictions-refuse_livestreams = livestreams_ablehnen
+    .description = Ob Livestreams abgelehnt werden
+command-settings-restrictions-refuse_age_restricted = altersbeschränkte_ablehnen
+    .description = Ob altersbeschränkte Inhalte abgelehnt werden
+command-settings-view = anzeigen
+    .description = Zeigt die Boteinstellungen für diesen Server
+command-settings-set = festlegen
+    .description = Ändert die Boteinstellungen für diesen Server
+command-settings-set-volume = lautstärke
+    .description = Lautstärke neuer Titel in Prozent
+command-settings-set-language = sprache
+    .description = Die Sprache, pro Person folgt der Discord-Sprache jeder Person
+command-settings-set-language-user = Pro Person
+command-settings-set-dj_role = dj_rolle
+    .description = Rolle, die die Warteschlange verwalten darf
+command-settings-set-announcement_channel = ankündigungskanal
+    .description = Kanal für Ankündigungen laufender Titel statt des Kanals des Befehls
+command-settings-set-idle_timeout = wartezeit
+    .description = Minuten nach Ende der Warteschlange, `immediate` oder `never`
+command-settings-set-max_queue_length = warteschlangenlänge
+    .description = Maximale Anzahl Titel in der Warteschlange, 0 entfernt das Limit
+command-settings-set-max_user_tracks = titel_pro_person
+    .description = Maximale Anzahl an Titeln pro Person in der Warteschlange, 0 entfernt das Limit
+command-settings-set-max_duration = maximale_dauer
+    .description = Maximale Dauer eines Titels in Minuten, 0 entfernt das Limit
+command-settings-set-vote_skip_threshold = abstimmungsschwelle
+    .description = Anteil der Zuhörer, der zum Überspringen abstimmen muss, 0 erlaubt es allen
+command-settings-set-announce_now_playing = titel_ankündigen
+    .description = Ob eingereihte Titel beim Start angekündigt werden
+command-settings-reset = zurücksetzen
+    .description = Setzt eine Boteinstellung auf den Standard zurück
+command-settings-reset-setting = einstellung
+    .description = Zurückzusetzende Einstellung
+command-settings-reset-setting-all = Alle Einstellungen
+command-settings-reset-setting-volume = Lautstärke
+command-settings-reset-setting-language = Sprache
+command-settings-reset-setting-dj_role = DJ-Rolle
+command-settings-reset-setting-announcement_channel = Ankündigungskanal
+command-settings-reset-setting-idle_timeout = Verlassen nach Ende der Warteschlange
+command-settings-reset-setting-max_queue_length = Maximale Warteschlangenlänge
+command-settings-reset-setting-max_user_tracks = Titel pro Person
+command-settings-reset-setting-max_duration = Maximale Titeldauer
+command-settings-reset-setting-vote_skip_threshold = Benötigte Stimmen zum Überspringen
+command-settings-reset-setting-announce_now_playing = Ankündigung laufender Titel
@@ -0,0 +1,225 @@
+## Messages
+
+language-name = English
+
+unknown-command = Unknown command
+unknown-title = Unknown title
+artist = Artist
+author = Author
+duration = Duration
+queue-length = Queue length
+queue-position = Position in queue
+now-playing = Now playing
+added-to-queue = Added to queue
+queue = Queue
+requested-by = Requested by
+failed-to-fetch = Failed to fetch track info
+failed-to-join = Failed to join voice channel
+failed-to-play = Failed to play track
+command-only-in-guild = This command can only be used in a server.
+user-must-be-in-voice-channel = You must be in a voice channel.
+bot-must-be-in-voice-channel = Bot must be in a voice channel.
+queue-empty = Queue is empty.
+failed-to-skip = Failed to skip track.
+skipped = Track skipped.
+shuffled = Queue shuffled.
+disconnected = Disconnected.
+failed-to-disconnect = Failed to disconnect.
+cleared-queue = Cleared queue.
+failed-to-change-channel = Failed to change voice channel.
+changed-channel = Changed voice channel.
+no-such-file = No such file found.
+statistics = Statistics
+top-tracks = Top tracks
+top-requesters = Top requesters
+total-listening-time = Total listening time
+no-statistics = No tracks were played in the selected period.
+stay-enabled = Bot will stay in this voice channel.
+stay-disabled = Bot will no longer stay in the voice channel.
+queue-restored = Queue restored after restart.
+restarting = Bot is restarting, the queue will be restored.
+skip-vote-registered = Voted to skip the track ({ $votes }/{ $required } { $required ->
+        [one] vote
+       *[other] votes
+    }).
+missing-permissions = Only a DJ, a server manager or the person who requested these tracks can use this command.
+missing-server-permissions = You don't have the server permissions needed to use this command.
+settings-updated = Settings updated.
+limit-out-of-range = Limit must be between 0 and { $max }.
+server-settings = Server settings
+setting-volume = Volume
+setting-language = Language
+setting-dj-role = DJ role
+setting-announcement-channel = Announcement channel
+setting-idle-timeout = Leave after queue ends
+setting-max-queue-length = Maximum queue length
+setting-vote-skip-threshold = Votes needed to skip
+setting-announce-now-playing = Now playing announcements
+setting-max-user-tracks = Tracks per user
+setting-max-duration = Maximum track duration
+setting-fair-queue = Fair queue
+setting-refuse-livestreams = Refuse livestreams
+setting-refuse-age-restricted = Refuse age-restricted content
+setting-allowed-domains = Allowed domains
+setting-blocked-domains = Blocked domains
+setting-blocked-keywords = Blocked keywords
+setting-blocked-uploaders = Blocked uploaders
+per-user = Per user
+not-set = Not set
+command-channel = Channel of the command
+immediately = Immediately
+never = Never
+unlimited = Unlimited
+on = On
+off = Off
+invalid-idle-timeout = Timeout must be a number of minutes, `immediate` or `never`.
+track-too-long = Track is too long, maximum duration is { $max }.
+queue-full = Queue is full, maximum queue length is { $max }.
+user-track-limit = You already have the maximum number of tracks in the queue ({ $max }).
+domain-not-allowed = Playing from { $domain } is not allowed on this server.
+blocked-keyword = Track title contains a blocked phrase.
+blocked-uploader = Tracks from { $uploader } are blocked on this server.
+livestream-not-allowed = Livestreams are not allowed on this server.
+age-restricted = Age-restricted content is not allowed on this server.
+yt-dlp-missing = Fetching tracks is unavailable, yt-dlp was not found.
+fetch-timeout = Fetching track info took too long.
+unsupported-url = This URL is not supported.
+video-unavailable = This track is unavailable.
+geo-blocked = This track is not available in this country.
+login-required = This track requires signing in.
+private-video = This track is private.
+age-verification-required = This track requires age verification.
+rate-limited = The service is rate limiting requests, try again later.
+extractor-failed = Failed to read the track page.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } track
+   *[other] { $count } tracks
+}
+plays = { $count ->
+    [one] { $count } play
+   *[other] { $count } plays
+}
+duration-hours = { $count ->
+    [one] { $count } hour
+   *[other] { $count } hours
+}
+duration-minutes = { $count ->
+    [one] { $count } minute
+   *[other] { $count } minutes
+}
+duration-seconds = { $count ->
+    [one] { $count } second
+   *[other] { $count } seconds
+}
+relative-hours = { $count ->
+    [one] in { $count } hour
+   *[other] in { $count } hours
+}
+relative-minutes = { $count ->
+    [one] in { $count } minute
+   *[other] in { $count } minutes
+}
+relative-seconds = { $count ->
+    [one] in { $count } second
+   *[other] in { $count } seconds
+}
+starts = Starts
+
+## Commands
+
+command-play = play
+    .description = Plays music from given url or search term
+command-play-query = query
+    .description = The URL or search term to play
+command-skip = skip
+    .description = Skips the currently playing track
+command-disconnect = disconnect
+    .description = Disconnects from the voice channel
+command-change_channel = change_channel
+    .description = Joins the voice channel of requester
+command-queue = queue
+    .description = Displays the queue
+command-clear_queue = clear_queue
+    .description = Clears queue
+command-shuffle = shuffle
+    .description = Shuffles queue
+command-play_local = play_local
+    .description = Plays content from curated list
+command-play_local-query = query
+    .description = The name of a file to play
+command-stats = stats
+    .description = Shows listening statistics of this server
+command-stats-period = period
+    .description = The period to show statistics for
+command-stats-period-week = Last week
+command-stats-period-month = Last month
+command-stats-period-all = All time
+command-stay = stay
+    .description = Toggles staying in the voice channel permanently
+command-settings = settings
+    .description = Changes bot settings for this server
+command-settings-fair_queue = fair_queue
+    .description = Alternates requesters in the queue instead of adding tracks to the end
+command-settings-fair_queue-enabled = enabled
+    .description = Whether fair queue is enabled
+command-settings-filter_add = filter_add
+    .description = Adds an entry to a content filter list
+command-settings-filter_remove = filter_remove
+    .description = Removes an entry from a content filter list
+command-settings-filter-list = list
+    .description = The filter list
+command-settings-filter-list-allowed_domains = Allowed domains
+command-settings-filter-list-blocked_domains = Blocked domains
+command-settings-filter-list-blocked_keywords = Blocked keywords
+command-settings-filter-list-blocked_uploaders = Blocked uploaders
+command-settings-filter-value = value
+    .description = Domain, keyword or uploader
+command-settings-restrictions = restrictions
+    .description = Sets whether livestreams and age-restricted content are refused
+command-settings-restrictions-refuse_livestreams = refuse_livestreams
+    .description = Whether livestreams are refused
+command-settings-restrictions-refuse_age_restricted = refuse_age_restricted
+    .description = Whether age-restricted content is refused
+command-settings-view = view
+    .description = Shows bot settings for this server
+command-settings-set = set
+    .description = Changes bot settings for this server
+command-settings-set-volume = volume
+    .description = Volume of new tracks in percent
+command-settings-set-language = language
+    .description = The language, per user follows each person's Discord language
+command-settings-set-language-user = Per user
+command-settings-set-dj_role = dj_role
+    .description = Role that can manage the queue
+command-settings-set-announcement_channel = announcement_channel
+    .description = Channel for now playing announcements instead of the channel of the command
+command-settings-set-idle_timeout = idle_timeout
+    .description = Minutes to stay after the queue ends, `immediate` or `never`
+command-settings-set-max_queue_length = max_queue_length
+    .description = Maximum number of tracks in the queue, 0 removes the limit
+command-settings-set-max_user_tracks = max_user_tracks
+    .description = Maximum number of queued tracks per user, 0 removes the limit
+command-settings-set-max_duration = max_duration
+    .description = Maximum track duration in minutes, 0 removes the limit
+command-settings-set-vote_skip_threshold = vote_skip_threshold
+    .description = Fraction of listeners that must vote to skip, 0 lets anyone skip
+command-settings-set-announce_now_playing = announce_now_playing
+    .description = Whether queued tracks are announced when they start
+command-settings-reset = reset
+    .description = Restores a bot setting to its default
+command-settings-reset-setting = setting
+    .description = Setting to restore
+command-settings-reset-setting-all = All settings
+command-settings-reset-setting-volume = Volume
+command-settings-reset-setting-language = Language
+command-settings-reset-setting-dj_role = DJ role
+command-settings-reset-setting-announcement_channel = Announcement channel
+command-settings-reset-setting-idle_timeout = Leave after queue ends
+command-settings-reset-setting-max_queue_length = Maximum queue length
+command-settings-reset-setting-max_user_tracks = Tracks per user
+command-settings-reset-setting-max_duration = Maximum track duration
+command-settings-reset-setting-vote_skip_threshold = Votes needed to skip
+command-settings-reset-setting-announce_now_playing = Now playing announcements
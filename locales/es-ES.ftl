@@ -0,0 +1,225 @@
+## Messages
+
+language-name = Español
+
+unknown-command = Comando desconocido
+unknown-title = Título desconocido
+artist = Artista
+author = Autor
+duration = Duración
+queue-length = Longitud de la cola
+queue-position = Posición en la cola
+now-playing = Reproduciendo ahora
+added-to-queue = Añadido a la cola
+queue = Cola
+requested-by = Pedido por
+failed-to-fetch = No se pudo obtener la información de la pista
+failed-to-join = No se pudo entrar al canal de voz
+failed-to-play = No se pudo reproducir la pista
+command-only-in-guild = Este comando solo se puede usar en un servidor.
+user-must-be-in-voice-channel = Debes estar en un canal de voz.
+bot-must-be-in-voice-channel = El bot debe estar en un canal de voz.
+queue-empty = La cola está vacía.
+failed-to-skip = No se pudo saltar la pista.
+skipped = Pista saltada.
+shuffled = Cola mezclada.
+disconnected = Desconectado.
+failed-to-disconnect = No se pudo desconectar.
+cleared-queue = Cola vaciada.
+failed-to-change-channel = No se pudo cambiar de canal de voz.
+changed-channel = Canal de voz cambiado.
+no-such-file = No se encontró el archivo.
+statistics = Estadísticas
+top-tracks = Pistas más escuchadas
+top-requesters = Oyentes más activos
+total-listening-time = Tiempo total de escucha
+no-statistics = No se reprodujo ninguna pista en el periodo seleccionado.
+stay-enabled = El bot se quedará en este canal de voz.
+stay-disabled = El bot ya no se quedará en el canal de voz.
+queue-restored = Cola restaurada tras el reinicio.
+restarting = El bot se está reiniciando, la cola se restaurará.
+skip-vote-registered = Has votado saltar la pista ({ $votes }/{ $required } { $required ->
+        [one] voto
+       *[other] votos
+    }).
+missing-permissions = Solo un DJ, un administrador del servidor o quien pidió estas pistas puede usar este comando.
+missing-server-permissions = No tienes los permisos del servidor necesarios para usar este comando.
+settings-updated = Ajustes actualizados.
+limit-out-of-range = El límite debe estar entre 0 y { $max }.
+server-settings = Ajustes del servidor
+setting-volume = Volumen
+setting-language = Idioma
+setting-dj-role = Rol de DJ
+setting-announcement-channel = Canal de anuncios
+setting-idle-timeout = Salir al terminar la cola
+setting-max-queue-length = Longitud máxima de la cola
+setting-vote-skip-threshold = Votos necesarios para saltar
+setting-announce-now-playing = Anuncios de pistas en reproducción
+setting-max-user-tracks = Pistas por usuario
+setting-max-duration = Duración máxima de pista
+setting-fair-queue = Cola justa
+setting-refuse-livestreams = Rechazar directos
+setting-refuse-age-restricted = Rechazar contenido con restricción de edad
+setting-allowed-domains = Dominios permitidos
+setting-blocked-domains = Dominios bloqueados
+setting-blocked-keywords = Palabras bloqueadas
+setting-blocked-uploaders = Autores bloqueados
+per-user = Por usuario
+not-set = Sin establecer
+command-channel = Canal del comando
+immediately = Inmediatamente
+never = Nunca
+unlimited = Sin límite
+on = Activado
+off = Desactivado
+invalid-idle-timeout = El tiempo debe ser un número de minutos, `immediate` o `never`.
+track-too-long = La pista es demasiado larga, la duración máxima es { $max }.
+queue-full = La cola está llena, la longitud máxima es { $max }.
+user-track-limit = Ya tienes el número máximo de pistas en la cola ({ $max }).
+domain-not-allowed = No se permite reproducir desde { $domain } en este servidor.
+blocked-keyword = El título de la pista contiene una frase bloqueada.
+blocked-uploader = Las pistas de { $uploader } están bloqueadas en este servidor.
+livestream-not-allowed = Las transmisiones en directo no están permitidas en este servidor.
+age-restricted = El contenido con restricción de edad no está permitido en este servidor.
+yt-dlp-missing = No se pueden obtener pistas, no se encontró yt-dlp.
+fetch-timeout = Obtener la información de la pista tardó demasiado.
+unsupported-url = Esta URL no es compatible.
+video-unavailable = Esta pista no está disponible.
+geo-blocked = Esta pista no está disponible en este país.
+login-required = Esta pista requiere iniciar sesión.
+private-video = Esta pista es privada.
+age-verification-required = Esta pista requiere verificar la edad.
+rate-limited = El servicio está limitando las peticiones, inténtalo más tarde.
+extractor-failed = No se pudo leer la página de la pista.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } pista
+   *[other] { $count } pistas
+}
+plays = { $count ->
+    [one] { $count } reproducción
+   *[other] { $count } reproducciones
+}
+duration-hours = { $count ->
+    [one] { $count } hora
+   *[other] { $count } horas
+}
+duration-minutes = { $count ->
+    [one] { $count } minuto
+   *[other] { $count } minutos
+}
+duration-seconds = { $count ->
+    [one] { $count } segundo
+   *[other] { $count } segundos
+}
+relative-hours = { $count ->
+    [one] en { $count } hora
+   *[other] en { $count } horas
+}
+relative-minutes = { $count ->
+    [one] en { $count } minuto
+   *[other] en { $count } minutos
+}
+relative-seconds = { $count ->
+    [one] en { $count } segundo
+   *[other] en { $count } segundos
+}
+starts = Empieza
+
+## Commands
+
+command-play = reproducir
+    .description = Reproduce música desde una URL o un término de búsqueda
+command-play-query = consulta
+    .description = La URL o el término de búsqueda
+command-skip = saltar
+    .description = Salta la pista actual
+command-disconnect = desconectar
+    .description = Sale del canal de voz
+command-change_channel = cambiar_canal
+    .description = Entra al canal de voz de quien lo pide
+command-queue = cola
+    .description = Muestra la cola
+command-clear_queue = vaciar_cola
+    .description = Vacía la cola
+command-shuffle = mezclar
+    .description = Mezcla la cola
+command-play_local = reproducir_local
+    .description = Reproduce contenido de la lista preparada
+command-play_local-query = consulta
+    .description = El nombre del archivo a reproducir
+command-stats = estadísticas
+    .description = Muestra las estadísticas de escucha de este servidor
+command-stats-period = periodo
+    .description = El periodo de las estadísticas
+command-stats-period-week = Última semana
+command-stats-period-month = Último mes
+command-stats-period-all = Todo el tiempo
+command-stay = quedarse
+    .description = Activa o desactiva quedarse en el canal de voz de forma permanente
+command-settings = ajustes
+    .description = Cambia los ajustes del bot en este servidor
+command-settings-fair_queue = cola_justa
+    .description = Alterna entre quienes piden pistas en lugar de añadirlas al final
+command-settings-fair_queue-enabled = activada
+    .description = Si la cola justa está activada
+command-settings-filter_add = añadir_filtro
+    .description = Añade una entrada a una lista de filtros de contenido
+command-settings-filter_remove = quitar_filtro
+    .description = Quita una entrada de una lista de filtros de contenido
+command-settings-filter-list = lista
+    .description = La lista de filtros
+command-settings-filter-list-allowed_domains = Dominios permitidos
+command-settings-filter-list-blocked_domains = Dominios bloqueados
+command-settings-filter-list-blocked_keywords = Frases bloqueadas
+command-settings-filter-list-blocked_uploaders = Autores bloqueados
+command-settings-filter-value = valor
+    .description = Dominio, frase o autor
+command-settings-restrictions = restricciones
+    .description = Establece si se rechazan las transmisiones en directo y el contenido con restricción de edad
+command-settings-restrictions-refuse_livestreams = rechazar_directos
+    .description = Si se rechazan las transmisiones en directo
+command-settings-restrictions-refuse_age_restricted = rechazar_restringidos_edad
+    .description = Si se rechaza el contenido con restricción de edad
+command-settings-view = ver
+    .description = Muestra los ajustes del bot en este servidor
+command-settings-set = establecer
+    .description = Cambia los ajustes del bot en este servidor
+command-settings-set-volume = volumen
+    .description = Volumen de las nuevas pistas en porcentaje
+command-settings-set-language = idioma
+    .description = El idioma, por usuario sigue el idioma de Discord de cada persona
+command-settings-set-language-user = Por usuario
+command-settings-set-dj_role = rol_dj
+    .description = Rol que puede gestionar la cola
+command-settings-set-announcement_channel = canal_anuncios
+    .description = Canal para anunciar las pistas en lugar del canal del comando
+command-settings-set-idle_timeout = tiempo_salida
+    .description = Minutos de espera al terminar la cola, `immediate` o `never`
+command-settings-set-max_queue_length = longitud_cola
+    .description = Número máximo de pistas en la cola, 0 elimina el límite
+command-settings-set-max_user_tracks = pistas_por_usuario
+    .description = Número máximo de pistas en la cola por usuario, 0 elimina el límite
+command-settings-set-max_duration = duración_máxima
+    .description = Duración máxima de una pista en minutos, 0 elimina el límite
+command-settings-set-vote_skip_threshold = umbral_votos
+    .description = Fracción de oyentes que debe votar para saltar, 0 permite saltar a todos
+command-settings-set-announce_now_playing = anunciar_pistas
+    .description = Si se anuncian las pistas de la cola al empezar
+command-settings-reset = restablecer
+    .description = Restablece un ajuste del bot a su valor predeterminado
+command-settings-reset-setting = ajuste
+    .description = Ajuste a restablecer
+command-settings-reset-setting-all = Todos los ajustes
+command-settings-reset-setting-volume = Volumen
+command-settings-reset-setting-language = Idioma
+command-settings-reset-setting-dj_role = Rol de DJ
+command-settings-reset-setting-announcement_channel = Canal de anuncios
+command-settings-reset-setting-idle_timeout = Salir al terminar la cola
+command-settings-reset-setting-max_queue_length = Longitud máxima de la cola
+command-settings-reset-setting-max_user_tracks = Pistas por usuario
+command-settings-reset-setting-max_duration = Duración máxima de pista
+command-settings-reset-setting-vote_skip_threshold = Votos necesarios para saltar
+command-settings-reset-setting-announce_now_playing = Anuncios de pistas en reproducción
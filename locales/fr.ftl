@@ -0,0 +1,225 @@
+## Messages
+
+language-name = Français
+
+unknown-command = Commande inconnue
+unknown-title = Titre inconnu
+artist = Artiste
+author = Auteur
+duration = Durée
+queue-length = Longueur de la file
+queue-position = Position dans la file
+now-playing = En cours de lecture
+added-to-queue = Ajouté à la file
+queue = File d'attente
+requested-by = Demandé par
+failed-to-fetch = Impossible de récupérer les informations du morceau
+failed-to-join = Impossible de rejoindre le salon vocal
+failed-to-play = Impossible de lire le morceau
+command-only-in-guild = Cette commande ne peut être utilisée que sur un serveur.
+user-must-be-in-voice-channel = Vous devez être dans un salon vocal.
+bot-must-be-in-voice-channel = Le bot doit être dans un salon vocal.
+queue-empty = La file d'attente est vide.
+failed-to-skip = Impossible de passer le morceau.
+skipped = Morceau passé.
+shuffled = File d'attente mélangée.
+disconnected = Déconnecté.
+failed-to-disconnect = Impossible de se déconnecter.
+cleared-queue = File d'attente vidée.
+failed-to-change-channel = Impossible de changer de salon vocal.
+changed-channel = Salon vocal changé.
+no-such-file = Fichier introuvable.
+statistics = Statistiques
+top-tracks = Morceaux les plus écoutés
+top-requesters = Auditeurs les plus actifs
+total-listening-time = Temps d'écoute total
+no-statistics = Aucun morceau n'a été lu pendant la période choisie.
+stay-enabled = Le bot restera dans ce salon vocal.
+stay-disabled = Le bot ne restera plus dans le salon vocal.
+queue-restored = File d'attente restaurée après le redémarrage.
+restarting = Le bot redémarre, la file d'attente sera restaurée.
+skip-vote-registered = Vote pour passer le morceau enregistré ({ $votes }/{ $required } { $required ->
+        [one] vote
+       *[other] votes
+    }).
+missing-permissions = Seul un DJ, un gestionnaire du serveur ou la personne ayant demandé ces morceaux peut utiliser cette commande.
+missing-server-permissions = Vous n'avez pas les permissions du serveur nécessaires pour utiliser cette commande.
+settings-updated = Paramètres mis à jour.
+limit-out-of-range = La limite doit être comprise entre 0 et { $max }.
+server-settings = Paramètres du serveur
+setting-volume = Volume
+setting-language = Langue
+setting-dj-role = Rôle DJ
+setting-announcement-channel = Salon des annonces
+setting-idle-timeout = Départ à la fin de la file
+setting-max-queue-length = Longueur maximale de la file
+setting-vote-skip-threshold = Votes nécessaires pour passer
+setting-announce-now-playing = Annonce des morceaux en cours
+setting-max-user-tracks = Morceaux par personne
+setting-max-duration = Durée maximale d'un morceau
+setting-fair-queue = File équitable
+setting-refuse-livestreams = Refuser les directs
+setting-refuse-age-restricted = Refuser le contenu soumis à une limite d'âge
+setting-allowed-domains = Domaines autorisés
+setting-blocked-domains = Domaines bloqués
+setting-blocked-keywords = Mots-clés bloqués
+setting-blocked-uploaders = Auteurs bloqués
+per-user = Par utilisateur
+not-set = Non défini
+command-channel = Salon de la commande
+immediately = Immédiatement
+never = Jamais
+unlimited = Illimitée
+on = Activé
+off = Désactivé
+invalid-idle-timeout = Le délai doit être un nombre de minutes, `immediate` ou `never`.
+track-too-long = Le morceau est trop long, la durée maximale est de { $max }.
+queue-full = La file d'attente est pleine, la longueur maximale est de { $max }.
+user-track-limit = Vous avez déjà le nombre maximal de morceaux dans la file ({ $max }).
+domain-not-allowed = La lecture depuis { $domain } n'est pas autorisée sur ce serveur.
+blocked-keyword = Le titre du morceau contient une expression bloquée.
+blocked-uploader = Les morceaux de { $uploader } sont bloqués sur ce serveur.
+livestream-not-allowed = Les diffusions en direct ne sont pas autorisées sur ce serveur.
+age-restricted = Les contenus soumis à une limite d'âge ne sont pas autorisés sur ce serveur.
+yt-dlp-missing = La récupération des morceaux est indisponible, yt-dlp est introuvable.
+fetch-timeout = La récupération des informations du morceau a pris trop de temps.
+unsupported-url = Cette URL n'est pas prise en charge.
+video-unavailable = Ce morceau est indisponible.
+geo-blocked = Ce morceau n'est pas disponible dans ce pays.
+login-required = Ce morceau nécessite une connexion.
+private-video = Ce morceau est privé.
+age-verification-required = Ce morceau nécessite une vérification de l'âge.
+rate-limited = Le service limite les requêtes, réessayez plus tard.
+extractor-failed = Impossible de lire la page du morceau.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } morceau
+   *[other] { $count } morceaux
+}
+plays = { $count ->
+    [one] { $count } écoute
+   *[other] { $count } écoutes
+}
+duration-hours = { $count ->
+    [one] { $count } heure
+   *[other] { $count } heures
+}
+duration-minutes = { $count ->
+    [one] { $count } minute
+   *[other] { $count } minutes
+}
+duration-seconds = { $count ->
+    [one] { $count } seconde
+   *[other] { $count } secondes
+}
+relative-hours = { $count ->
+    [one] dans { $count } heure
+   *[other] dans { $count } heures
+}
+relative-minutes = { $count ->
+    [one] dans { $count } minute
+   *[other] dans { $count } minutes
+}
+relative-seconds = { $count ->
+    [one] dans { $count } seconde
+   *[other] dans { $count } secondes
+}
+starts = Commence
+
+## Commands
+
+command-play = jouer
+    .description = Joue de la musique depuis une URL ou une recherche
+command-play-query = recherche
+    .description = L'URL ou les termes de recherche
+command-skip = passer
+    .description = Passe le morceau en cours
+command-disconnect = déconnecter
+    .description = Quitte le salon vocal
+command-change_channel = changer_salon
+    .description = Rejoint le salon vocal de la personne qui le demande
+command-queue = file
+    .description = Affiche la file d'attente
+command-clear_queue = vider_file
+    .description = Vide la file d'attente
+command-shuffle = mélanger
+    .description = Mélange la file d'attente
+command-play_local = jouer_local
+    .description = Joue un contenu de la liste préparée
+command-play_local-query = recherche
+    .description = Le nom du fichier à jouer
+command-stats = statistiques
+    .description = Affiche les statistiques d'écoute de ce serveur
+command-stats-period = période
+    .description = La période des statistiques
+command-stats-period-week = Semaine dernière
+command-stats-period-month = Mois dernier
+command-stats-period-all = Depuis toujours
+command-stay = rester
+    .description = Active ou désactive la présence permanente dans le salon vocal
+command-settings = paramètres
+    .description = Modifie les paramètres du bot sur ce serveur
+command-settings-fair_queue = file_équitable
+    .description = Alterne entre les personnes au lieu d'ajouter les morceaux à la fin
+command-settings-fair_queue-enabled = activée
+    .description = Si la file équitable est activée
+command-settings-filter_add = ajouter_filtre
+    .description = Ajoute une entrée à une liste de filtres de contenu
+command-settings-filter_remove = retirer_filtre
+    .description = Retire une entrée d'une liste de filtres de contenu
+command-settings-filter-list = liste
+    .description = La liste de filtres
+command-settings-filter-list-allowed_domains = Domaines autorisés
+command-settings-filter-list-blocked_domains = Domaines bloqués
+command-settings-filter-list-blocked_keywords = Expressions bloquées
+command-settings-filter-list-blocked_uploaders = Auteurs bloqués
+command-settings-filter-value = valeur
+    .description = Domaine, expression ou auteur
+command-settings-restrictions = restrictions
+    .description = Définit si les directs et les contenus soumis à une limite d'âge sont refusés
+command-settings-restrictions-refuse_livestreams = refuser_directs
+    .description = Si les diffusions en direct sont refusées
+command-settings-restrictions-refuse_age_restricted = refuser_limite_âge
+    .description = Si les contenus soumis à une limite d'âge sont refusés
+command-settings-view = afficher
+    .description = Affiche les paramètres du bot sur ce serveur
+command-settings-set = définir
+    .description = Modifie les paramètres du bot sur ce serveur
+command-settings-set-volume = volume
+    .description = Volume des nouveaux morceaux en pourcentage
+command-settings-set-language = langue
+    .description = La langue, par utilisateur suit la langue Discord de chaque personne
+command-settings-set-language-user = Par utilisateur
+command-settings-set-dj_role = rôle_dj
+    .description = Rôle qui peut gérer la file d'attente
+command-settings-set-announcement_channel = salon_annonces
+    .description = Salon des annonces de morceaux au lieu du salon de la commande
+command-settings-set-idle_timeout = délai_départ
+    .description = Minutes d'attente à la fin de la file, `immediate` ou `never`
+command-settings-set-max_queue_length = longueur_file
+    .description = Nombre maximal de morceaux dans la file, 0 retire la limite
+command-settings-set-max_user_tracks = morceaux_par_personne
+    .description = Nombre maximal de morceaux dans la file par personne, 0 retire la limite
+command-settings-set-max_duration = durée_max
+    .description = Durée maximale d'un morceau en minutes, 0 retire la limite
+command-settings-set-vote_skip_threshold = seuil_vote
+    .description = Part des auditeurs qui doit voter pour passer, 0 permet à tous de passer
+command-settings-set-announce_now_playing = annoncer_morceaux
+    .description = Annoncer ou non le début des morceaux de la file
+command-settings-reset = réinitialiser
+    .description = Rétablit un paramètre du bot à sa valeur par défaut
+command-settings-reset-setting = paramètre
+    .description = Paramètre à rétablir
+command-settings-reset-setting-all = Tous les paramètres
+command-settings-reset-setting-volume = Volume
+command-settings-reset-setting-language = Langue
+command-settings-reset-setting-dj_role = Rôle DJ
+command-settings-reset-setting-announcement_channel = Salon des annonces
+command-settings-reset-setting-idle_timeout = Départ à la fin de la file
+command-settings-reset-setting-max_queue_length = Longueur maximale de la file
+command-settings-reset-setting-max_user_tracks = Morceaux par personne
+command-settings-reset-setting-max_duration = Durée maximale d'un morceau
+command-settings-reset-setting-vote_skip_threshold = Votes nécessaires pour passer
+command-settings-reset-setting-announce_now_playing = Annonce des morceaux en cours
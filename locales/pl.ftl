@@ -0,0 +1,234 @@
+## Messages
+
+language-name = Polski
+
+unknown-command = Nieznane polecenie
+unknown-title = Nieznany tytuł
+artist = Artysta
+author = Autor
+duration = Czas trwania
+queue-length = Długość kolejki
+queue-position = Pozycja w kolejce
+now-playing = Teraz odtwarzane
+added-to-queue = Dodano do kolejki
+queue = Kolejka
+requested-by = Dodane przez
+failed-to-fetch = Nie udało się pobrać informacji o utworze
+failed-to-join = Nie udało się dołączyć do kanału głosowego
+failed-to-play = Nie udało się odtworzyć utworu
+command-only-in-guild = To polecenie może być używane tylko na serwerze.
+user-must-be-in-voice-channel = Musisz być na kanale głosowym.
+bot-must-be-in-voice-channel = Bot musi być na kanale głosowym.
+queue-empty = Kolejka jest pusta.
+failed-to-skip = Nie udało się pominąć utworu.
+skipped = Utwór pominięty.
+shuffled = Kolejka została przetasowana.
+disconnected = Rozłączono.
+failed-to-disconnect = Nie udało się rozłączyć.
+cleared-queue = Kolejka została wyczyszczona.
+failed-to-change-channel = Nie udało się zmienić kanału głosowego.
+changed-channel = Zmieniono kanał głosowy.
+no-such-file = Nie znaleziono takiego pliku.
+statistics = Statystyki
+top-tracks = Najczęściej odtwarzane utwory
+top-requesters = Najaktywniejsi słuchacze
+total-listening-time = Łączny czas słuchania
+no-statistics = Brak odtworzeń w wybranym okresie.
+stay-enabled = Bot pozostanie na tym kanale głosowym.
+stay-disabled = Bot nie będzie już pozostawał na kanale głosowym.
+queue-restored = Przywrócono kolejkę po ponownym uruchomieniu.
+restarting = Bot jest uruchamiany ponownie, kolejka zostanie przywrócona.
+skip-vote-registered = Zagłosowano za pominięciem utworu ({ $votes }/{ $required } { $required ->
+        [one] głos
+        [few] głosy
+       *[many] głosów
+    }).
+missing-permissions = Tylko DJ, zarządzający serwerem lub osoba, która dodała te utwory, może użyć tego polecenia.
+missing-server-permissions = Nie masz uprawnień na serwerze potrzebnych do użycia tego polecenia.
+settings-updated = Zaktualizowano ustawienia.
+limit-out-of-range = Limit musi wynosić od 0 do { $max }.
+server-settings = Ustawienia serwera
+setting-volume = Głośność
+setting-language = Język
+setting-dj-role = Rola DJ
+setting-announcement-channel = Kanał ogłoszeń
+setting-idle-timeout = Wyjście po końcu kolejki
+setting-max-queue-length = Maksymalna długość kolejki
+setting-vote-skip-threshold = Głosy potrzebne do pominięcia
+setting-announce-now-playing = Ogłaszanie odtwarzanych utworów
+setting-max-user-tracks = Utwory na osobę
+setting-max-duration = Maksymalny czas utworu
+setting-fair-queue = Sprawiedliwa kolejka
+setting-refuse-livestreams = Odrzucanie transmisji na żywo
+setting-refuse-age-restricted = Odrzucanie treści z ograniczeniem wiekowym
+setting-allowed-domains = Dozwolone domeny
+setting-blocked-domains = Zablokowane domeny
+setting-blocked-keywords = Zablokowane frazy
+setting-blocked-uploaders = Zablokowani autorzy
+per-user = Dla każdego
+not-set = Nie ustawiono
+command-channel = Kanał komendy
+immediately = Od razu
+never = Nigdy
+unlimited = Bez limitu
+on = Włączone
+off = Wyłączone
+invalid-idle-timeout = Czas musi być liczbą minut, `immediate` lub `never`.
+track-too-long = Utwór jest za długi, maksymalny czas trwania to { $max }.
+queue-full = Kolejka jest pełna, maksymalna długość kolejki to { $max }.
+user-track-limit = Masz już w kolejce maksymalną liczbę utworów ({ $max }).
+domain-not-allowed = Odtwarzanie z { $domain } nie jest dozwolone na tym serwerze.
+blocked-keyword = Tytuł utworu zawiera zablokowaną frazę.
+blocked-uploader = Utwory od { $uploader } są zablokowane na tym serwerze.
+livestream-not-allowed = Transmisje na żywo nie są dozwolone na tym serwerze.
+age-restricted = Treści z ograniczeniem wiekowym nie są dozwolone na tym serwerze.
+yt-dlp-missing = Pobieranie utworów jest niedostępne, nie znaleziono yt-dlp.
+fetch-timeout = Pobieranie informacji o utworze trwało zbyt długo.
+unsupported-url = Ten adres URL nie jest obsługiwany.
+video-unavailable = Ten utwór jest niedostępny.
+geo-blocked = Ten utwór jest niedostępny w tym kraju.
+login-required = Ten utwór wymaga zalogowania.
+private-video = Ten utwór jest prywatny.
+age-verification-required = Ten utwór wymaga potwierdzenia wieku.
+rate-limited = Serwis ogranicza liczbę zapytań, spróbuj ponownie później.
+extractor-failed = Nie udało się odczytać strony z utworem.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } utwór
+    [few] { $count } utwory
+   *[many] { $count } utworów
+}
+plays = { $count ->
+    [one] { $count } odtworzenie
+    [few] { $count } odtworzenia
+   *[many] { $count } odtworzeń
+}
+duration-hours = { $count ->
+    [one] { $count } godzina
+    [few] { $count } godziny
+   *[many] { $count } godzin
+}
+duration-minutes = { $count ->
+    [one] { $count } minuta
+    [few] { $count } minuty
+   *[many] { $count } minut
+}
+duration-seconds = { $count ->
+    [one] { $count } sekunda
+    [few] { $count } sekundy
+   *[many] { $count } sekund
+}
+relative-hours = { $count ->
+    [one] za { $count } godzinę
+    [few] za { $count } godziny
+   *[many] za { $count } godzin
+}
+relative-minutes = { $count ->
+    [one] za { $count } minutę
+    [few] za { $count } minuty
+   *[many] za { $count } minut
+}
+relative-seconds = { $count ->
+    [one] za { $count } sekundę
+    [few] za { $count } sekundy
+   *[many] za { $count } sekund
+}
+starts = Początek
+
+## Commands
+
+command-play = graj
+    .description = Odtwarza muzykę z podanego adresu URL lub wyszukiwanej frazy
+command-play-query = zapytanie
+    .description = Adres URL lub wyszukiwana fraza do odtwarzania
+command-skip = pomiń
+    .description = Niezwłocznie przechodzi do następnego utworu
+command-disconnect = rozłącz
+    .description = Rozłącza z kanału głosowego
+command-change_channel = zmień_kanał
+    .description = Przechodzi do kanału głosowego żądającego
+command-queue = kolejka
+    .description = Wyświetla kolejkę
+command-clear_queue = wyczyść_kolejkę
+    .description = Czyści kolejkę
+command-shuffle = przetasuj
+    .description = Przetasowuje kolejkę
+command-play_local = graj_lokalne
+    .description = Odtwarza zawartość z przygotowanej listy
+command-play_local-query = zapytanie
+    .description = Nazwa pliku do odtworzenia
+command-stats = statystyki
+    .description = Wyświetla statystyki słuchania na tym serwerze
+command-stats-period = okres
+    .description = Okres, dla którego wyświetlić statystyki
+command-stats-period-week = Ostatni tydzień
+command-stats-period-month = Ostatni miesiąc
+command-stats-period-all = Cały czas
+command-stay = zostań
+    .description = Przełącza stałe pozostawanie na kanale głosowym
+command-settings = ustawienia
+    .description = Zmienia ustawienia bota na tym serwerze
+command-settings-fair_queue = sprawiedliwa_kolejka
+    .description = Przeplata utwory różnych osób zamiast dodawać je na koniec kolejki
+command-settings-fair_queue-enabled = włączona
+    .description = Czy sprawiedliwa kolejka jest włączona
+command-settings-filter_add = dodaj_filtr
+    .description = Dodaje wpis do listy filtrów treści
+command-settings-filter_remove = usuń_filtr
+    .description = Usuwa wpis z listy filtrów treści
+command-settings-filter-list = lista
+    .description = Lista filtrów
+command-settings-filter-list-allowed_domains = Dozwolone domeny
+command-settings-filter-list-blocked_domains = Zablokowane domeny
+command-settings-filter-list-blocked_keywords = Zablokowane frazy
+command-settings-filter-list-blocked_uploaders = Zablokowani autorzy
+command-settings-filter-value = wartość
+    .description = Domena, fraza lub autor
+command-settings-restrictions = ograniczenia
+    .description = Ustawia, czy transmisje na żywo i treści z ograniczeniem wiekowym są odrzucane
+command-settings-restrictions-refuse_livestreams = odrzucaj_transmisje
+    .description = Czy transmisje na żywo są odrzucane
+command-settings-restrictions-refuse_age_restricted = odrzucaj_ograniczone_wiekowo
+    .description = Czy treści z ograniczeniem wiekowym są odrzucane
+command-settings-view = pokaż
+    .description = Pokazuje ustawienia bota na tym serwerze
+command-settings-set = ustaw
+    .description = Zmienia ustawienia bota na tym serwerze
+command-settings-set-volume = głośność
+    .description = Głośność nowych utworów w procentach
+command-settings-set-language = język
+    .description = Język, „dla każdego” używa języka Discorda danej osoby
+command-settings-set-language-user = Dla każdego
+command-settings-set-dj_role = rola_dj
+    .description = Rola, która może zarządzać kolejką
+command-settings-set-announcement_channel = kanał_ogłoszeń
+    .description = Kanał na ogłoszenia odtwarzanych utworów zamiast kanału komendy
+command-settings-set-idle_timeout = czas_wyjścia
+    .description = Minuty pozostania po końcu kolejki, `immediate` lub `never`
+command-settings-set-max_queue_length = długość_kolejki
+    .description = Maksymalna liczba utworów w kolejce, 0 usuwa limit
+command-settings-set-max_user_tracks = utwory_na_osobę
+    .description = Maksymalna liczba utworów w kolejce na osobę, 0 usuwa limit
+command-settings-set-max_duration = maksymalny_czas
+    .description = Maksymalny czas trwania utworu w minutach, 0 usuwa limit
+command-settings-set-vote_skip_threshold = próg_głosowania
+    .description = Część słuchaczy, która musi zagłosować za pominięciem, 0 pozwala pomijać każdemu
+command-settings-set-announce_now_playing = ogłaszaj_utwory
+    .description = Czy ogłaszać rozpoczęcie utworów z kolejki
+command-settings-reset = resetuj
+    .description = Przywraca domyślną wartość ustawienia bota
+command-settings-reset-setting = ustawienie
+    .description = Ustawienie do przywrócenia
+command-settings-reset-setting-all = Wszystkie ustawienia
+command-settings-reset-setting-volume = Głośność
+command-settings-reset-setting-language = Język
+command-settings-reset-setting-dj_role = Rola DJ
+command-settings-reset-setting-announcement_channel = Kanał ogłoszeń
+command-settings-reset-setting-idle_timeout = Wyjście po końcu kolejki
+command-settings-reset-setting-max_queue_length = Maksymalna długość kolejki
+command-settings-reset-setting-max_user_tracks = Utwory na osobę
+command-settings-reset-setting-max_duration = Maksymalny czas utworu
+command-settings-reset-setting-vote_skip_threshold = Głosy potrzebne do pominięcia
+command-settings-reset-setting-announce_now_playing = Ogłaszanie odtwarzanych utworów
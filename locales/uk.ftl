@@ -0,0 +1,234 @@
+## Messages
+
+language-name = Українська
+
+unknown-command = Невідома команда
+unknown-title = Невідома назва
+artist = Виконавець
+author = Автор
+duration = Тривалість
+queue-length = Довжина черги
+queue-position = Місце в черзі
+now-playing = Зараз грає
+added-to-queue = Додано до черги
+queue = Черга
+requested-by = Додав(ла)
+failed-to-fetch = Не вдалося отримати інформацію про трек
+failed-to-join = Не вдалося приєднатися до голосового каналу
+failed-to-play = Не вдалося відтворити трек
+command-only-in-guild = Цю команду можна використовувати лише на сервері.
+user-must-be-in-voice-channel = Ви маєте бути в голосовому каналі.
+bot-must-be-in-voice-channel = Бот має бути в голосовому каналі.
+queue-empty = Черга порожня.
+failed-to-skip = Не вдалося пропустити трек.
+skipped = Трек пропущено.
+shuffled = Чергу перемішано.
+disconnected = Відключено.
+failed-to-disconnect = Не вдалося відключитися.
+cleared-queue = Чергу очищено.
+failed-to-change-channel = Не вдалося змінити голосовий канал.
+changed-channel = Голосовий канал змінено.
+no-such-file = Такий файл не знайдено.
+statistics = Статистика
+top-tracks = Найпопулярніші треки
+top-requesters = Найактивніші слухачі
+total-listening-time = Загальний час прослуховування
+no-statistics = За вибраний період не було відтворено жодного треку.
+stay-enabled = Бот залишатиметься в цьому голосовому каналі.
+stay-disabled = Бот більше не залишатиметься в голосовому каналі.
+queue-restored = Чергу відновлено після перезапуску.
+restarting = Бот перезапускається, чергу буде відновлено.
+skip-vote-registered = Ви проголосували за пропуск треку ({ $votes }/{ $required } { $required ->
+        [one] голос
+        [few] голоси
+       *[many] голосів
+    }).
+missing-permissions = Цю команду може використати лише DJ, адміністратор сервера або особа, яка додала ці треки.
+missing-server-permissions = У вас немає дозволів на сервері, потрібних для цієї команди.
+settings-updated = Налаштування оновлено.
+limit-out-of-range = Обмеження має бути від 0 до { $max }.
+server-settings = Налаштування сервера
+setting-volume = Гучність
+setting-language = Мова
+setting-dj-role = Роль DJ
+setting-announcement-channel = Канал оголошень
+setting-idle-timeout = Вихід після кінця черги
+setting-max-queue-length = Максимальна довжина черги
+setting-vote-skip-threshold = Голоси для пропуску
+setting-announce-now-playing = Оголошення треків, що грають
+setting-max-user-tracks = Треки на людину
+setting-max-duration = Максимальна тривалість треку
+setting-fair-queue = Чесна черга
+setting-refuse-livestreams = Відхиляти трансляції
+setting-refuse-age-restricted = Відхиляти вміст з віковими обмеженнями
+setting-allowed-domains = Дозволені домени
+setting-blocked-domains = Заблоковані домени
+setting-blocked-keywords = Заблоковані фрази
+setting-blocked-uploaders = Заблоковані автори
+per-user = Для кожного
+not-set = Не встановлено
+command-channel = Канал команди
+immediately = Одразу
+never = Ніколи
+unlimited = Без обмежень
+on = Увімкнено
+off = Вимкнено
+invalid-idle-timeout = Час має бути кількістю хвилин, `immediate` або `never`.
+track-too-long = Трек задовгий, максимальна тривалість — { $max }.
+queue-full = Черга заповнена, максимальна довжина черги — { $max }.
+user-track-limit = У вас уже максимальна кількість треків у черзі ({ $max }).
+domain-not-allowed = Відтворення з { $domain } заборонено на цьому сервері.
+blocked-keyword = Назва треку містить заблоковану фразу.
+blocked-uploader = Треки від { $uploader } заблоковано на цьому сервері.
+livestream-not-allowed = Прямі трансляції заборонено на цьому сервері.
+age-restricted = Вміст із віковими обмеженнями заборонено на цьому сервері.
+yt-dlp-missing = Отримання треків недоступне, yt-dlp не знайдено.
+fetch-timeout = Отримання інформації про трек тривало надто довго.
+unsupported-url = Ця URL-адреса не підтримується.
+video-unavailable = Цей трек недоступний.
+geo-blocked = Цей трек недоступний у цій країні.
+login-required = Для цього треку потрібно увійти.
+private-video = Цей трек приватний.
+age-verification-required = Для цього треку потрібне підтвердження віку.
+rate-limited = Сервіс обмежує кількість запитів, спробуйте пізніше.
+extractor-failed = Не вдалося прочитати сторінку треку.
+
+## Formatting
+
+tracks = { $count ->
+    [one] { $count } трек
+    [few] { $count } треки
+   *[many] { $count } треків
+}
+plays = { $count ->
+    [one] { $count } відтворення
+    [few] { $count } відтворення
+   *[many] { $count } відтворень
+}
+duration-hours = { $count ->
+    [one] { $count } година
+    [few] { $count } години
+   *[many] { $count } годин
+}
+duration-minutes = { $count ->
+    [one] { $count } хвилина
+    [few] { $count } хвилини
+   *[many] { $count } хвилин
+}
+duration-seconds = { $count ->
+    [one] { $count } секунда
+    [few] { $count } секунди
+   *[many] { $count } секунд
+}
+relative-hours = { $count ->
+    [one] через { $count } годину
+    [few] через { $count } години
+   *[many] через { $count } годин
+}
+relative-minutes = { $count ->
+    [one] через { $count } хвилину
+    [few] через { $count } хвилини
+   *[many] через { $count } хвилин
+}
+relative-seconds = { $count ->
+    [one] через { $count } секунду
+    [few] через { $count } секунди
+   *[many] через { $count } секунд
+}
+starts = Початок
+
+## Commands
+
+command-play = грати
+    .description = Відтворює музику за URL-адресою або пошуковим запитом
+command-play-query = запит
+    .description = URL-адреса або пошуковий запит
+command-skip = пропустити
+    .description = Пропускає поточний трек
+command-disconnect = відключитися
+    .description = Виходить із голосового каналу
+command-change_channel = змінити_канал
+    .description = Переходить до голосового каналу того, хто просить
+command-queue = черга
+    .description = Показує чергу
+command-clear_queue = очистити_чергу
+    .description = Очищає чергу
+command-shuffle = перемішати
+    .description = Перемішує чергу
+command-play_local = грати_локально
+    .description = Відтворює вміст із підготовленого списку
+command-play_local-query = запит
+    .description = Назва файлу для відтворення
+command-stats = статистика
+    .description = Показує статистику прослуховування на цьому сервері
+command-stats-period = період
+    .description = Період, за який показати статистику
+command-stats-period-week = Останній тиждень
+command-stats-period-month = Останній місяць
+command-stats-period-all = Весь час
+command-stay = залишитися
+    .description = Перемикає постійне перебування в голосовому каналі
+command-settings = налаштування
+    .description = Змінює налаштування бота на цьому сервері
+command-settings-fair_queue = чесна_черга
+    .description = Чергує треки різних людей замість додавання в кінець черги
+command-settings-fair_queue-enabled = увімкнено
+    .description = Чи увімкнено чесну чергу
+command-settings-filter_add = додати_фільтр
+    .description = Додає запис до списку фільтрів вмісту
+command-settings-filter_remove = видалити_фільтр
+    .description = Видаляє запис зі списку фільтрів вмісту
+command-settings-filter-list = список
+    .description = Список фільтрів
+command-settings-filter-list-allowed_domains = Дозволені домени
+command-settings-filter-list-blocked_domains = Заблоковані домени
+command-settings-filter-list-blocked_keywords = Заблоковані фрази
+command-settings-filter-list-blocked_uploaders = Заблоковані автори
+command-settings-filter-value = значення
+    .description = Домен, фраза або автор
+command-settings-restrictions = обмеження
+    .description = Встановлює, чи відхиляються прямі трансляції та вміст із віковими обмеженнями
+command-settings-restrictions-refuse_livestreams = відхиляти_трансляції
+    .description = Чи відхиляються прямі трансляції
+command-settings-restrictions-refuse_age_restricted = відхиляти_вікові_обмеження
+    .description = Чи відхиляється вміст із віковими обмеженнями
+command-settings-view = показати
+    .description = Показує налаштування бота на цьому сервері
+command-settings-set = встановити
+    .description = Змінює налаштування бота на цьому сервері
+command-settings-set-volume = гучність
+    .description = Гучність нових треків у відсотках
+command-settings-set-language = мова
+    .description = Мова, «для кожного» використовує мову Discord кожної людини
+command-settings-set-language-user = Для кожного
+command-settings-set-dj_role = роль_dj
+    .description = Роль, яка може керувати чергою
+command-settings-set-announcement_channel = канал_оголошень
+    .description = Канал для оголошень треків замість каналу команди
+command-settings-set-idle_timeout = час_виходу
+    .description = Хвилини очікування після кінця черги, `immediate` або `never`
+command-settings-set-max_queue_length = довжина_черги
+    .description = Максимальна кількість треків у черзі, 0 прибирає обмеження
+command-settings-set-max_user_tracks = треків_на_особу
+    .description = Максимальна кількість треків у черзі на людину, 0 прибирає обмеження
+command-settings-set-max_duration = макс_тривалість
+    .description = Максимальна тривалість треку в хвилинах, 0 прибирає обмеження
+command-settings-set-vote_skip_threshold = поріг_голосування
+    .description = Частка слухачів, що має проголосувати за пропуск, 0 дозволяє пропускати всім
+command-settings-set-announce_now_playing = оголошувати_треки
+    .description = Чи оголошувати початок треків з черги
+command-settings-reset = скинути
+    .description = Повертає налаштування бота до типового значення
+command-settings-reset-setting = налаштування
+    .description = Налаштування, яке треба скинути
+command-settings-reset-setting-all = Усі налаштування
+command-settings-reset-setting-volume = Гучність
+command-settings-reset-setting-language = Мова
+command-settings-reset-setting-dj_role = Роль DJ
+command-settings-reset-setting-announcement_channel = Канал оголошень
+command-settings-reset-setting-idle_timeout = Вихід після кінця черги
+command-settings-reset-setting-max_queue_length = Максимальна довжина черги
+command-settings-reset-setting-max_user_tracks = Треки на людину
+command-settings-reset-setting-max_duration = Максимальна тривалість треку
+command-settings-reset-setting-vote_skip_threshold = Голоси для пропуску
+command-settings-reset-setting-announce_now_playing = Оголошення треків, що грають
@@ -1,70 +1,81 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
+
+use tracing::error;
 
 use crate::utils::{
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
+    permissions::is_allowed,
+    audio::get_user_channel,
+    stay::StayChannels,
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
-            return;
-        }
-    };
+use super::{
+    SlashCommand,
+    voice_handler,
+};
 
-    let voice_states = match guild_id.to_guild_cached(&ctx.cache) {
-        Some(guild) => Some(guild.voice_states.clone()),
-        None => None,
-    };
+pub struct ChangeChannel;
+
+#[async_trait]
+impl SlashCommand for ChangeChannel {
+    fn name(&self) -> &'static str {
+        "change_channel"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
 
-    let voice_states = match voice_states {
-        Some(states) => states,
-        None => {
-            normal_response(ctx, command, Text::FailedToChangeChannel.into()).await;
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let Some(handler_lock) = voice_handler(ctx, command, guild_id).await else {
             return;
-        }
-    };
+        };
 
-    let channel_id = match voice_states.get(&command.user.id) {
-        Some(state) => match state.channel_id {
-            Some(id) => id,
-            None => {
-                normal_response(ctx, command, Text::UserMustBeInVoiceChannel.into()).await;
+        let channel_id = match get_user_channel(ctx, guild_id, command.user.id) {
+            Ok(channel_id) => channel_id,
+            Err(why) => {
+                normal_response(ctx, command, why.into()).await;
                 return;
             }
-        },
-        None => {
-            normal_response(ctx, command, Text::UserMustBeInVoiceChannel.into()).await;
+        };
+
+        let queue = handler_lock.lock().await.queue().current_queue();
+
+        if !is_allowed(ctx, command, &queue).await {
+            normal_response(ctx, command, Text::MissingPermissions.into()).await;
             return;
         }
-    };
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.");
+        // Joining through the handler keeps its queue and events. The lock is released before waiting,
+        // like `Songbird::join` does.
+        let joining = handler_lock.lock().await.join(channel_id).await;
 
-    if let None = manager.get(guild_id) {
-        normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
-        return;
-    };
+        let result = match joining {
+            Ok(join) => join.await,
+            Err(why) => Err(why),
+        };
 
-    match manager.join(guild_id, channel_id).await {
-        Ok(_) => normal_response(ctx, command, Text::ChangedChannel.into()).await,
-        Err(why) => {
-            eprintln!("Failed to change voice channel: {why:?}");
-            normal_response(ctx, command, Text::FailedToChangeChannel.into()).await;
-        },
-    }
+        match result {
+            Ok(_) => {
+                ctx.data.write().await
+                    .get_mut::<StayChannels>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .move_to(guild_id, channel_id);
 
+                normal_response(ctx, command, Text::ChangedChannel.into()).await;
+            },
+            Err(why) => {
+                error!(error = ?why, "Failed to change voice channel");
+                normal_response(ctx, command, Text::FailedToChangeChannel.into()).await;
+            },
+        }
+    }
 }
-
-pub fn register() -> CreateCommand {
-    CreateCommand::new("change_channel")
-        .description("Joins the voice channel of requester")
-        .name_localized("pl", "zmień_kanał")
-        .description_localized("pl", "Przechodzi do kanału głosowego żądającego")
-}
\ No newline at end of file
@@ -1,53 +1,63 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
 
 use crate::utils::{
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
+    queue_state,
+    permissions::is_allowed,
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
+use super::{
+    SlashCommand,
+    voice_handler,
+};
+
+pub struct ClearQueue;
+
+#[async_trait]
+impl SlashCommand for ClearQueue {
+    fn name(&self) -> &'static str {
+        "clear_queue"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let Some(handler_lock) = voice_handler(ctx, command, guild_id).await else {
             return;
-        }
-    };
+        };
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.")
-        .clone();
+        let queue = handler_lock.lock().await.queue().current_queue();
 
-    let handler_lock = match manager.get(guild_id) {
-        Some(handler) => handler,
-        None => {
-            normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+        if queue.len() <= 1 {
+            normal_response(ctx, command, Text::QueueEmpty.into()).await;
             return;
-        },
-    };
+        }
 
-    let handler = handler_lock.lock().await;
+        if !is_allowed(ctx, command, &queue[1..]).await {
+            normal_response(ctx, command, Text::MissingPermissions.into()).await;
+            return;
+        }
 
-    if handler.queue().is_empty() || handler.queue().len() == 1 {
-        normal_response(ctx, command, Text::QueueEmpty.into()).await;
-        return;
-    }
-    
-    handler.queue().modify_queue(|queue| {
-        queue.drain(1..);
-    });
+        let handler = handler_lock.lock().await;
 
-    drop(handler);
+        handler.queue().modify_queue(|queue| {
+            queue.drain(1..);
+        });
 
-    normal_response(ctx, command, Text::ClearedQueue.into()).await;
-}
+        drop(handler);
+
+        queue_state::save(&ctx.data, guild_id).await;
 
-pub fn register() -> CreateCommand {
-    CreateCommand::new("clear_queue")
-        .description("Clears queue")
-        .name_localized("pl", "wyczyść_kolejkę")
-        .description_localized("pl", "Czyści kolejkę")
-}
\ No newline at end of file
+        normal_response(ctx, command, Text::ClearedQueue.into()).await;
+    }
+}
@@ -1,37 +1,65 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
+
+use tracing::error;
 
 use crate::utils::{
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
+    stay::StayChannels,
+    queue_state,
+    permissions::is_allowed,
+};
+
+use super::{
+    SlashCommand,
+    manager,
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
+pub struct Disconnect;
+
+#[async_trait]
+impl SlashCommand for Disconnect {
+    fn name(&self) -> &'static str {
+        "disconnect"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let manager = manager(ctx).await;
+
+        let queue = match manager.get(guild_id) {
+            Some(handler_lock) => handler_lock.lock().await.queue().current_queue(),
+            None => Vec::new(),
+        };
+
+        if !is_allowed(ctx, command, &queue).await {
+            normal_response(ctx, command, Text::MissingPermissions.into()).await;
             return;
         }
-    };
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.");
+        ctx.data.write().await
+            .get_mut::<StayChannels>()
+            .expect("Guaranteed to exist in the typemap.")
+            .remove(guild_id);
+
+        queue_state::remove(&ctx.data, guild_id).await;
 
-    match manager.leave(guild_id).await {
-        Ok(_) => normal_response(ctx, command, Text::Disconnected.into()).await,
-        Err(why) => {
-            eprintln!("Failed to disconnect: {why:?}");
-            normal_response(ctx, command, Text::FailedToDisconnect.into()).await;
+        match manager.leave(guild_id).await {
+            Ok(_) => normal_response(ctx, command, Text::Disconnected.into()).await,
+            Err(why) => {
+                error!(error = ?why, "Failed to disconnect");
+                normal_response(ctx, command, Text::FailedToDisconnect.into()).await;
+            }
         }
     }
 }
-
-pub fn register() -> CreateCommand {
-    CreateCommand::new("disconnect")
-        .description("Disconnects from the voice channel")
-        .name_localized("pl", "rozłącz")
-        .description_localized("pl", "Rozłącza z kanału głosowego")
-}
\ No newline at end of file
@@ -5,4 +5,146 @@ pub mod change_channel;
 pub mod queue;
 pub mod clear_queue;
 pub mod shuffle;
-pub mod play_local;
\ No newline at end of file
+pub mod play_local;
+pub mod stats;
+pub mod stay;
+pub mod settings;
+
+use std::sync::Arc;
+
+use serenity::{
+    all::{
+        CommandInteraction,
+        ComponentInteraction,
+        CreateCommand,
+        GuildId,
+        InteractionContext,
+        Permissions,
+    },
+    async_trait,
+    prelude::*,
+};
+
+use songbird::{
+    Call,
+    Songbird,
+};
+
+use crate::utils::{
+    response::normal_response,
+    localization::Text,
+};
+
+/// Slash command handled by the bot, registered and dispatched through [`COMMANDS`].
+#[async_trait]
+pub trait SlashCommand: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn register(&self) -> CreateCommand;
+
+    /// Runs the command in a guild.
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId);
+
+    /// Runs the command outside of a guild, only reachable when [`Self::guild_only`] is false.
+    async fn run_outside_guild(&self, ctx: &Context, command: &CommandInteraction) {
+        normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
+    }
+
+    /// Returns choices for the focused option.
+    async fn autocomplete(&self, _ctx: &Context, _partial: &str) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Handles message components whose custom id starts with `<name>:`.
+    async fn component(&self, _ctx: &Context, _component: &ComponentInteraction) {}
+
+    fn required_permissions(&self) -> Option<Permissions> {
+        None
+    }
+
+    fn guild_only(&self) -> bool {
+        true
+    }
+}
+
+pub static COMMANDS: &[&dyn SlashCommand] = &[
+    &play::Play,
+    &skip::Skip,
+    &disconnect::Disconnect,
+    &change_channel::ChangeChannel,
+    &queue::Queue,
+    &clear_queue::ClearQueue,
+    &shuffle::Shuffle,
+    &play_local::PlayLocal,
+    &stats::Stats,
+    &stay::Stay,
+    &settings::Settings,
+];
+
+pub fn find(name: &str) -> Option<&'static dyn SlashCommand> {
+    COMMANDS.iter()
+        .find(|command| command.name() == name)
+        .copied()
+}
+
+pub fn register_all() -> Vec<CreateCommand> {
+    COMMANDS.iter()
+        .map(|command| {
+            let contexts = match command.guild_only() {
+                true => vec![InteractionContext::Guild],
+                false => vec![InteractionContext::Guild, InteractionContext::BotDm],
+            };
+
+            let builder = command.register().contexts(contexts);
+
+            match command.required_permissions() {
+                Some(permissions) => builder.default_member_permissions(permissions),
+                None => builder,
+            }
+        })
+        .collect()
+}
+
+pub async fn run(ctx: &Context, command: &CommandInteraction) {
+    let slash_command = match find(&command.data.name) {
+        Some(slash_command) => slash_command,
+        None => {
+            normal_response(ctx, command, Text::UnknownCommand.into()).await;
+            return;
+        }
+    };
+
+    if let Some(required) = slash_command.required_permissions() {
+        let permitted = command.member.as_ref()
+            .and_then(|member| member.permissions)
+            .is_some_and(|permissions| permissions.contains(required));
+
+        if !permitted {
+            normal_response(ctx, command, Text::MissingServerPermissions.into()).await;
+            return;
+        }
+    }
+
+    match command.guild_id {
+        Some(guild_id) => slash_command.run(ctx, command, guild_id).await,
+        None => slash_command.run_outside_guild(ctx, command).await,
+    }
+}
+
+pub async fn manager(ctx: &Context) -> Arc<Songbird> {
+    songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+}
+
+/// Returns the voice handler of the guild, responding that the bot must be in a voice channel if there is none.
+pub async fn voice_handler(ctx: &Context, command: &CommandInteraction, guild_id: GuildId) -> Option<Arc<Mutex<Call>>> {
+    let handler_lock = manager(ctx).await.get(guild_id);
+
+    if handler_lock.is_none() {
+        normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+    }
+
+    handler_lock
+}
+
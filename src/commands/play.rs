@@ -1,65 +1,72 @@
-use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::builder::CreateCommand;
 use serenity::model::application::{CommandInteraction, CommandOptionType};
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
-use std::sync::Arc;
+use serenity::async_trait;
+
+use tracing::error;
 
 use crate::utils::{
     audio::*,
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    if let Err(why) = command.defer(&ctx.http).await {
-        eprintln!("Failed to defer interaction: {why:?}");
-        normal_response(ctx, command, Text::FailedToPlay.into()).await;
-        return;
+use super::SlashCommand;
+
+pub struct Play;
+
+#[async_trait]
+impl SlashCommand for Play {
+    fn name(&self) -> &'static str {
+        "play"
     }
 
-    let channel_id = match get_channel_to_join(ctx, command) {
-        Ok(id) => id,
-        Err(err) => return edit_response(ctx, command, err.into()).await,
-    };
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+            .add_option(
+                localization::option(CommandOptionType::String, "play", "query")
+                    .required(true)
+            )
+    }
 
-    let (track, metadata) = match process_query(ctx, command).await {
-        Ok((mut track, metadata)) => {
-            if channel_id.is_none() {
-                track.user_data = Arc::new((metadata.clone(), Some(command.clone())));
-            } else {
-                track.user_data = Arc::new((metadata.clone(), None::<CommandInteraction>));
-            }
-            
-            (track, metadata)
-        },
-        Err(_) => {
-            edit_response(ctx, command, Text::FailedToFetch.into()).await;
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        if let Err(why) = command.defer(&ctx.http).await {
+            error!(error = ?why, "Failed to defer interaction");
+            normal_response(ctx, command, Text::FailedToPlay.into()).await;
             return;
-        },
-    };
+        }
+
+        let channel_id = match get_channel_to_join(ctx, command) {
+            Ok(id) => id,
+            Err(err) => return edit_response(ctx, command, err.into()).await,
+        };
 
+        let (track, metadata) = match process_query(ctx, command).await {
+            Ok(resolved) => resolved,
+            Err(why) => {
+                edit_response(ctx, command, Text::from(why).into()).await;
+                return;
+            },
+        };
 
-    if let Some(id) = channel_id {
-        if let Err(why) = join(ctx, command, id).await {
+        if let Err(why) = check_track(ctx, command, guild_id, &metadata, SourceKind::Remote).await {
             edit_response(ctx, command, why.into()).await;
             return;
         }
-    }
 
-    match play(ctx, command, track, metadata, channel_id.is_none()).await {
-        Ok(embed) => edit_response(ctx, command, embed.into()).await,
-        Err(why) => edit_response(ctx, command, why.into()).await,
+        if let Some(id) = channel_id
+            && let Err(why) = join(ctx, guild_id, id).await {
+            edit_response(ctx, command, why.into()).await;
+            return;
+        }
+
+        match play(ctx, command, track, metadata, SourceKind::Remote, channel_id.is_none()).await {
+            Ok(embed) => edit_response(ctx, command, embed.into()).await,
+            Err(why) => edit_response(ctx, command, why.into()).await,
+        }
     }
 }
-
-pub fn register() -> CreateCommand {
-    CreateCommand::new("play")
-        .description("Plays music from given url or search term")
-        .name_localized("pl", "graj")
-        .description_localized("pl", "Odtwarza muzykę z podanego adresu URL lub wyszukiwanej frazy")
-        .add_option(
-            CreateCommandOption::new(CommandOptionType::String, "query", "The URL or search term to play")
-                .name_localized("pl", "zapytanie")
-                .description_localized("pl", "Adres URL lub wyszukiwana fraza do odtwarzania")
-                .required(true)
-        )
-}
\ No newline at end of file
@@ -1,57 +1,56 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
 
 use crate::utils::{
     audio::*,
     response::*,
-    localization::Text,
+    settings::command_locale,
+    localization::{
+        self,
+        Text,
+    },
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
-            return;
-        }
-    };
+use super::{
+    SlashCommand,
+    voice_handler,
+};
+
+pub struct Queue;
+
+#[async_trait]
+impl SlashCommand for Queue {
+    fn name(&self) -> &'static str {
+        "queue"
+    }
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.")
-        .clone();
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
 
-    let handler_lock = match manager.get(guild_id) {
-        Some(handler) => handler,
-        None => {
-            normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let Some(handler_lock) = voice_handler(ctx, command, guild_id).await else {
             return;
-        },
-    };
+        };
 
-    let handler = handler_lock.lock().await;
+        let handler = handler_lock.lock().await;
 
-    if handler.queue().is_empty() || handler.queue().len() == 1 {
-        normal_response(ctx, command, Text::QueueEmpty.into()).await;
-        return;
-    }
+        if handler.queue().is_empty() || handler.queue().len() == 1 {
+            normal_response(ctx, command, Text::QueueEmpty.into()).await;
+            return;
+        }
 
-    let queue_metadata = handler.queue().current_queue().iter().skip(1).map(|handle| {
-        let data = handle.data::<(Metadata, Option<CommandInteraction>)>();
-        data.0.clone()
-    }).collect::<Vec<_>>();
+        let queue_data = handler.queue().current_queue().iter().skip(1).map(|handle| {
+            handle.data::<TrackData>()
+        }).collect::<Vec<_>>();
 
-    drop(handler);
+        drop(handler);
 
-    let embed = create_queue_embed(&queue_metadata, &command.locale);
+        let embed = create_queue_embed(&queue_data, &command_locale(&ctx.data, command).await);
 
-    normal_response(ctx, command, embed.into()).await;
+        normal_response(ctx, command, embed.into()).await;
+    }
 }
-
-pub fn register() -> CreateCommand {
-    CreateCommand::new("queue")
-        .description("Displays the queue")
-        .name_localized("pl", "kolejka")
-        .description_localized("pl", "Wyświetla kolejkę")
-}
\ No newline at end of file
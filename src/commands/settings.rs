@@ -0,0 +1,388 @@
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::{CommandDataOptionValue, CommandInteraction, CommandOptionType};
+use serenity::model::channel::ChannelType;
+use serenity::model::Permissions;
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use serenity::async_trait;
+
+use tracing::warn;
+
+use crate::utils::{
+    response::*,
+    localization::{
+        self,
+        Text,
+    },
+    cli::{
+        parse_idle_timeout,
+        Config,
+    },
+    settings::{
+        command_locale,
+        guild_settings,
+        Settings as SettingsStore,
+    },
+};
+
+use super::SlashCommand;
+
+const MAX_TRACK_LIMIT: i64 = 10_000;
+
+const MAX_DURATION_LIMIT: i64 = 24 * 60;
+
+pub struct Settings;
+
+#[async_trait]
+impl SlashCommand for Settings {
+    fn name(&self) -> &'static str {
+        "settings"
+    }
+
+    fn required_permissions(&self) -> Option<Permissions> {
+        Some(Permissions::MANAGE_GUILD)
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+            .add_option(
+                localization::option(CommandOptionType::SubCommand, "settings", "view")
+            )
+            .add_option(
+                localization::option(CommandOptionType::SubCommand, "settings", "set")
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Integer, "settings-set", "volume")
+                            .min_int_value(0)
+                            .max_int_value(200)
+                    )
+                    .add_sub_option(language_option())
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Role, "settings-set", "dj_role")
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Channel, "settings-set", "announcement_channel")
+                            .channel_types(vec![ChannelType::Text])
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::String, "settings-set", "idle_timeout")
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Integer, "settings-set", "max_queue_length")
+                            .min_int_value(0)
+                            .max_int_value(MAX_TRACK_LIMIT as u64)
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Integer, "settings-set", "max_user_tracks")
+                            .min_int_value(0)
+                            .max_int_value(MAX_TRACK_LIMIT as u64)
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Integer, "settings-set", "max_duration")
+                            .min_int_value(0)
+                            .max_int_value(MAX_DURATION_LIMIT as u64)
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Number, "settings-set", "vote_skip_threshold")
+                            .min_number_value(0.0)
+                            .max_number_value(1.0)
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Boolean, "settings-set", "announce_now_playing")
+                    )
+            )
+            .add_option(
+                localization::option(CommandOptionType::SubCommand, "settings", "reset")
+                    .add_sub_option(
+                        localization::string_choices(
+                            localization::option(CommandOptionType::String, "settings-reset", "setting"),
+                            "settings-reset-setting",
+                            &[
+                                "all",
+                                "volume",
+                                "language",
+                                "dj_role",
+                                "announcement_channel",
+                                "idle_timeout",
+                                "max_queue_length",
+                                "max_user_tracks",
+                                "max_duration",
+                                "vote_skip_threshold",
+                                "announce_now_playing",
+                            ],
+                        )
+                        .required(true)
+                    )
+            )
+            .add_option(
+                localization::option(CommandOptionType::SubCommand, "settings", "fair_queue")
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Boolean, "settings-fair_queue", "enabled")
+                            .required(true)
+                    )
+            )
+            .add_option(filter_options(
+                localization::option(CommandOptionType::SubCommand, "settings", "filter_add")
+            ))
+            .add_option(filter_options(
+                localization::option(CommandOptionType::SubCommand, "settings", "filter_remove")
+            ))
+            .add_option(
+                localization::option(CommandOptionType::SubCommand, "settings", "restrictions")
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Boolean, "settings-restrictions", "refuse_livestreams")
+                    )
+                    .add_sub_option(
+                        localization::option(CommandOptionType::Boolean, "settings-restrictions", "refuse_age_restricted")
+                    )
+            )
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let subcommand = match command.data.options.first() {
+            Some(option) => option,
+            None => {
+                warn!("No options found in {command:?}");
+                normal_response(ctx, command, Text::UnknownCommand.into()).await;
+                return;
+            }
+        };
+
+        let options = match &subcommand.value {
+            CommandDataOptionValue::SubCommand(options) => options,
+            value => {
+                warn!("Expected a subcommand, got: {value:?}");
+                normal_response(ctx, command, Text::UnknownCommand.into()).await;
+                return;
+            }
+        };
+
+        match subcommand.name.as_str() {
+            "view" => {
+                let settings = guild_settings(ctx, guild_id).await;
+
+                let config = ctx.data.read().await
+                    .get::<Config>()
+                    .cloned()
+                    .expect("Guaranteed to exist in the typemap.");
+
+                let embed = create_settings_embed(&settings, &config, &command_locale(&ctx.data, command).await);
+
+                normal_response(ctx, command, embed.into()).await;
+            },
+            "set" => {
+                let option = |name: &str| options.iter()
+                    .find(|option| option.name == name)
+                    .map(|option| &option.value);
+
+                let idle_timeout = match option("idle_timeout").and_then(|value| value.as_str()) {
+                    Some(value) => match parse_idle_timeout(value.trim()) {
+                        Ok(timeout) => Some(timeout),
+                        Err(_) => {
+                            normal_response(ctx, command, Text::InvalidIdleTimeout.into()).await;
+                            return;
+                        }
+                    },
+                    None => None,
+                };
+
+                // Discord enforces the ranges too, but out of range values must not clear a limit.
+                let limit = |name: &str, max: i64| match option(name).and_then(|value| value.as_i64()) {
+                    Some(value) if (0..=max).contains(&value) => Ok(u32::try_from(value).ok()),
+                    Some(_) => Err(Text::LimitOutOfRange(max as u64)),
+                    None => Ok(None),
+                };
+
+                let limits = (
+                    limit("max_queue_length", MAX_TRACK_LIMIT),
+                    limit("max_user_tracks", MAX_TRACK_LIMIT),
+                    limit("max_duration", MAX_DURATION_LIMIT),
+                );
+
+                let (max_queue_length, max_user_tracks, max_duration) = match limits {
+                    (Ok(max_queue_length), Ok(max_user_tracks), Ok(max_duration)) => (max_queue_length, max_user_tracks, max_duration),
+                    (Err(why), _, _) | (_, Err(why), _) | (_, _, Err(why)) => {
+                        normal_response(ctx, command, why.into()).await;
+                        return;
+                    }
+                };
+
+                let volume = option("volume").and_then(|value| value.as_i64());
+                let language = option("language").and_then(|value| value.as_str());
+                let dj_role = option("dj_role").and_then(|value| value.as_role_id());
+                let announcement_channel = option("announcement_channel").and_then(|value| value.as_channel_id());
+                let vote_skip_threshold = option("vote_skip_threshold").and_then(|value| value.as_f64());
+                let announce_now_playing = option("announce_now_playing").and_then(|value| value.as_bool());
+
+                ctx.data.write().await
+                    .get_mut::<SettingsStore>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .update(guild_id, |settings| {
+                        if let Some(volume) = volume {
+                            settings.volume = u8::try_from(volume).ok();
+                        }
+                        if let Some(language) = language {
+                            settings.language = (language != "user").then(|| language.to_string());
+                        }
+                        if let Some(role) = dj_role {
+                            settings.dj_role = Some(role);
+                        }
+                        if let Some(channel) = announcement_channel {
+                            settings.announcement_channel = Some(channel);
+                        }
+                        if let Some(timeout) = idle_timeout {
+                            settings.idle_timeout = Some(timeout);
+                        }
+                        if let Some(max) = max_queue_length {
+                            settings.max_queue_length = (max > 0).then_some(max as usize);
+                        }
+                        if let Some(max) = max_user_tracks {
+                            settings.max_user_tracks = (max > 0).then_some(max as usize);
+                        }
+                        if let Some(max) = max_duration {
+                            settings.max_duration = (max > 0).then_some(max).and_then(|max| max.checked_mul(60));
+                        }
+                        if let Some(threshold) = vote_skip_threshold {
+                            settings.vote_skip_threshold = Some(threshold);
+                        }
+                        if let Some(announce) = announce_now_playing {
+                            settings.announce_now_playing = Some(announce);
+                        }
+                    });
+
+                normal_response(ctx, command, Text::SettingsUpdated.into()).await;
+            },
+            "reset" => {
+                let setting = options.first()
+                    .and_then(|option| option.value.as_str())
+                    .unwrap_or_default();
+
+                let mut data = ctx.data.write().await;
+                let store = data.get_mut::<SettingsStore>()
+                    .expect("Guaranteed to exist in the typemap.");
+
+                let defaults = store.defaults().clone();
+
+                match setting {
+                    "all" => store.reset(guild_id),
+                    setting => store.update(guild_id, |settings| match setting {
+                        "volume" => settings.volume = defaults.volume,
+                        "language" => settings.language = defaults.language,
+                        "dj_role" => settings.dj_role = defaults.dj_role,
+                        "announcement_channel" => settings.announcement_channel = defaults.announcement_channel,
+                        "idle_timeout" => settings.idle_timeout = defaults.idle_timeout,
+                        "max_queue_length" => settings.max_queue_length = defaults.max_queue_length,
+                        "max_user_tracks" => settings.max_user_tracks = defaults.max_user_tracks,
+                        "max_duration" => settings.max_duration = defaults.max_duration,
+                        "vote_skip_threshold" => settings.vote_skip_threshold = defaults.vote_skip_threshold,
+                        "announce_now_playing" => settings.announce_now_playing = defaults.announce_now_playing,
+                        _ => {},
+                    }),
+                }
+
+                drop(data);
+
+                normal_response(ctx, command, Text::SettingsUpdated.into()).await;
+            },
+            "fair_queue" => {
+                let enabled = options.first()
+                    .and_then(|option| option.value.as_bool())
+                    .unwrap_or_default();
+
+                ctx.data.write().await
+                    .get_mut::<SettingsStore>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .update(guild_id, |settings| settings.fair_queue = enabled);
+
+                normal_response(ctx, command, Text::SettingsUpdated.into()).await;
+            },
+            "filter_add" | "filter_remove" => {
+                let option = |name: &str| options.iter()
+                    .find(|option| option.name == name)
+                    .and_then(|option| option.value.as_str())
+                    .map(|value| value.trim().to_lowercase());
+
+                let (list, value) = match (option("list"), option("value")) {
+                    (Some(list), Some(value)) if !value.is_empty() => (list, value),
+                    _ => {
+                        normal_response(ctx, command, Text::UnknownCommand.into()).await;
+                        return;
+                    }
+                };
+
+                let add = subcommand.name == "filter_add";
+
+                ctx.data.write().await
+                    .get_mut::<SettingsStore>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .update(guild_id, |settings| {
+                        let list = match list.as_str() {
+                            "allowed_domains" => &mut settings.allowed_domains,
+                            "blocked_domains" => &mut settings.blocked_domains,
+                            "blocked_keywords" => &mut settings.blocked_keywords,
+                            _ => &mut settings.blocked_uploaders,
+                        };
+
+                        list.retain(|entry| *entry != value);
+                        if add {
+                            list.push(value);
+                        }
+                    });
+
+                normal_response(ctx, command, Text::SettingsUpdated.into()).await;
+            },
+            "restrictions" => {
+                let option = |name: &str| options.iter()
+                    .find(|option| option.name == name)
+                    .and_then(|option| option.value.as_bool());
+
+                let livestreams = option("refuse_livestreams");
+                let age_restricted = option("refuse_age_restricted");
+
+                ctx.data.write().await
+                    .get_mut::<SettingsStore>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .update(guild_id, |settings| {
+                        if let Some(refuse) = livestreams {
+                            settings.refuse_livestreams = refuse;
+                        }
+                        if let Some(refuse) = age_restricted {
+                            settings.refuse_age_restricted = refuse;
+                        }
+                    });
+
+                normal_response(ctx, command, Text::SettingsUpdated.into()).await;
+            },
+            _ => normal_response(ctx, command, Text::UnknownCommand.into()).await,
+        }
+    }
+}
+
+fn filter_options(subcommand: CreateCommandOption) -> CreateCommandOption {
+    subcommand
+        .add_sub_option(
+            localization::string_choices(
+                localization::option(CommandOptionType::String, "settings-filter", "list"),
+                "settings-filter-list",
+                &["allowed_domains", "blocked_domains", "blocked_keywords", "blocked_uploaders"],
+            )
+            .required(true)
+        )
+        .add_sub_option(
+            localization::option(CommandOptionType::String, "settings-filter", "value")
+                .required(true)
+        )
+}
+
+fn language_option() -> CreateCommandOption {
+    let mut option = localization::string_choices(
+        localization::option(CommandOptionType::String, "settings-set", "language"),
+        "settings-set-language",
+        &["user"],
+    );
+
+    for (locale, name) in localization::locales().languages() {
+        option = option.add_string_choice(name, locale);
+    }
+
+    option
+}
@@ -1,58 +1,68 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
 
 use rand::seq::SliceRandom;
 
 use crate::utils::{
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
+    queue_state,
+    permissions::is_allowed,
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
+use super::{
+    SlashCommand,
+    voice_handler,
+};
+
+pub struct Shuffle;
+
+#[async_trait]
+impl SlashCommand for Shuffle {
+    fn name(&self) -> &'static str {
+        "shuffle"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let Some(handler_lock) = voice_handler(ctx, command, guild_id).await else {
             return;
-        }
-    };
+        };
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.")
-        .clone();
+        let queue = handler_lock.lock().await.queue().current_queue();
 
-    let handler_lock = match manager.get(guild_id) {
-        Some(handler) => handler,
-        None => {
-            normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+        if queue.len() <= 1 {
+            normal_response(ctx, command, Text::QueueEmpty.into()).await;
             return;
-        },
-    };
+        }
 
-    let handler = handler_lock.lock().await;
+        if !is_allowed(ctx, command, &queue[1..]).await {
+            normal_response(ctx, command, Text::MissingPermissions.into()).await;
+            return;
+        }
 
-    if handler.queue().is_empty() || handler.queue().len() == 1 {
-        normal_response(ctx, command, Text::QueueEmpty.into()).await;
-        return;
-    }
-    
-    handler.queue().modify_queue(|queue| {
-        let mut rng = rand::rng();
-        let mut items = queue.drain(1..).collect::<Vec<_>>();
-        items.shuffle(&mut rng);
-        queue.extend(items);
-    });
+        let handler = handler_lock.lock().await;
 
-    drop(handler);
+        handler.queue().modify_queue(|queue| {
+            let mut rng = rand::rng();
+            let mut items = queue.drain(1..).collect::<Vec<_>>();
+            items.shuffle(&mut rng);
+            queue.extend(items);
+        });
 
-    normal_response(ctx, command, Text::Shuffled.into()).await;
-}
+        drop(handler);
+
+        queue_state::save(&ctx.data, guild_id).await;
 
-pub fn register() -> CreateCommand {
-    CreateCommand::new("shuffle")
-        .description("Shuffles queue")
-        .name_localized("pl", "przetasuj")
-        .description_localized("pl", "Przetasowuje kolejkę")
-}
\ No newline at end of file
+        normal_response(ctx, command, Text::Shuffled.into()).await;
+    }
+}
@@ -1,55 +1,114 @@
 use serenity::builder::CreateCommand;
 use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
 use serenity::prelude::Context;
+use serenity::async_trait;
+
+use tracing::error;
 
 use crate::utils::{
+    audio::*,
     response::*,
-    localization::Text,
+    localization::{
+        self,
+        Text,
+    },
+    cli::Config,
+    permissions::is_dj,
+    settings::Settings,
+    skip_votes::{
+        required_votes,
+        SkipVotes,
+    },
+};
+
+use super::{
+    SlashCommand,
+    voice_handler,
 };
 
-pub async fn run(ctx: &Context, command: &CommandInteraction) {
-    let guild_id = match command.guild_id {
-        Some(id) => id,
-        None => {
-            normal_response(ctx, command, Text::CommandOnlyInGuild.into()).await;
+pub struct Skip;
+
+#[async_trait]
+impl SlashCommand for Skip {
+    fn name(&self) -> &'static str {
+        "skip"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let Some(handler_lock) = voice_handler(ctx, command, guild_id).await else {
             return;
+        };
+
+        let current = match handler_lock.lock().await.queue().current() {
+            Some(current) => current,
+            None => {
+                normal_response(ctx, command, Text::QueueEmpty.into()).await;
+                return;
+            }
+        };
+
+        let threshold = {
+            let data = ctx.data.read().await;
+
+            let config = data.get::<Config>()
+                .expect("Guaranteed to exist in the typemap.");
+
+            data.get::<Settings>()
+                .expect("Guaranteed to exist in the typemap.")
+                .get(guild_id)
+                .vote_skip_threshold(config)
+        };
+
+        let is_requester = current.data::<TrackData>().requester.id == command.user.id;
+
+        if let Some(threshold) = threshold && !is_requester && !is_dj(ctx, command).await {
+            let listeners = get_bot_channel(ctx, guild_id)
+                .map(|channel_id| get_listeners(ctx, guild_id, channel_id))
+                .unwrap_or_default();
+
+            if !listeners.contains(&command.user.id) {
+                normal_response(ctx, command, Text::UserMustBeInVoiceChannel.into()).await;
+                return;
+            }
+
+            let votes = ctx.data.write().await
+                .get_mut::<SkipVotes>()
+                .expect("Guaranteed to exist in the typemap.")
+                .vote(guild_id, current.uuid(), command.user.id, &listeners);
+
+            let required = required_votes(listeners.len(), threshold);
+
+            if votes < required {
+                normal_response(ctx, command, Text::SkipVoteRegistered(votes, required).into()).await;
+                return;
+            }
         }
-    };
 
-    let manager = songbird::get(ctx)
-        .await
-        .expect("Songbird Voice client placed in at initialisation.")
-        .clone();
+        let handler = handler_lock.lock().await;
 
-    let handler_lock = match manager.get(guild_id) {
-        Some(handler) => handler,
-        None => {
-            normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+        if handler.queue().current().is_none_or(|track| track.uuid() != current.uuid()) {
+            normal_response(ctx, command, Text::FailedToSkip.into()).await;
             return;
-        },
-    };
-
-    let handler = handler_lock.lock().await;
+        }
 
-    if handler.queue().is_empty() {
-        normal_response(ctx, command, Text::QueueEmpty.into()).await;
-        return;
-    }
+        if let Err(why) = handler.queue().skip() {
+            error!(error = ?why, "Failed to skip track");
+            normal_response(ctx, command, Text::FailedToSkip.into()).await;
+            return;
+        }
 
-    if let Err(why) = handler.queue().skip() {
-        println!("Failed to skip track: {}", why);
-        normal_response(ctx, command, Text::FailedToSkip.into()).await;
-        return;
-    }
+        drop(handler);
 
-    drop(handler);
+        ctx.data.write().await
+            .get_mut::<SkipVotes>()
+            .expect("Guaranteed to exist in the typemap.")
+            .clear(guild_id);
 
-    normal_response(ctx, command, Text::Skipped.into()).await;
+        normal_response(ctx, command, Text::Skipped.into()).await;
+    }
 }
-
-pub fn register() -> CreateCommand {
-    CreateCommand::new("skip")
-        .description("Skips the currently playing track")
-        .name_localized("pl", "pomiń")
-        .description_localized("pl", "Niezwłocznie przechodzi do następnego utworu")
-}
\ No newline at end of file
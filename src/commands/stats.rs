@@ -0,0 +1,66 @@
+use serenity::builder::CreateCommand;
+use serenity::model::application::{CommandDataOptionValue, CommandInteraction, CommandOptionType};
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use serenity::async_trait;
+
+use crate::utils::{
+    localization,
+    response::*,
+    settings::command_locale,
+    play_log::{
+        now,
+        PlayLog,
+    },
+};
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+const MONTH: u64 = 30 * 24 * 60 * 60;
+
+use super::SlashCommand;
+
+pub struct Stats;
+
+#[async_trait]
+impl SlashCommand for Stats {
+    fn name(&self) -> &'static str {
+        "stats"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+            .add_option(
+                localization::string_choices(
+                    localization::option(CommandOptionType::String, "stats", "period"),
+                    "stats-period",
+                    &["week", "month", "all"],
+                )
+            )
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        let period = command.data.options.first()
+            .and_then(|option| match &option.value {
+                CommandDataOptionValue::String(period) => Some(period.as_str()),
+                _ => None,
+            })
+            .unwrap_or("all");
+
+        let since = match period {
+            "week" => now().saturating_sub(WEEK),
+            "month" => now().saturating_sub(MONTH),
+            _ => 0,
+        };
+
+        let statistics = {
+            let data = ctx.data.read().await;
+            data.get::<PlayLog>()
+                .expect("Guaranteed to exist in the typemap.")
+                .statistics(guild_id, since, 10)
+        };
+
+        let embed = create_stats_embed(&statistics, &command_locale(&ctx.data, command).await);
+
+        normal_response(ctx, command, embed.into()).await;
+    }
+}
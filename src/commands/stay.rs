@@ -0,0 +1,84 @@
+use serenity::builder::CreateCommand;
+use serenity::model::application::CommandInteraction;
+use serenity::model::id::GuildId;
+use serenity::prelude::Context;
+use serenity::async_trait;
+
+use crate::utils::{
+    audio::*,
+    response::*,
+    localization::{
+        self,
+        Text,
+    },
+    idle,
+    permissions::is_allowed,
+    stay::{
+        play_fallback,
+        StayChannels,
+    },
+};
+
+use super::SlashCommand;
+
+pub struct Stay;
+
+#[async_trait]
+impl SlashCommand for Stay {
+    fn name(&self) -> &'static str {
+        "stay"
+    }
+
+    fn register(&self) -> CreateCommand {
+        localization::command(self.name())
+    }
+
+    async fn run(&self, ctx: &Context, command: &CommandInteraction, guild_id: GuildId) {
+        if !is_allowed(ctx, command, &[]).await {
+            normal_response(ctx, command, Text::MissingPermissions.into()).await;
+            return;
+        }
+
+        let removed = ctx.data.write().await
+            .get_mut::<StayChannels>()
+            .expect("Guaranteed to exist in the typemap.")
+            .remove(guild_id);
+
+        if removed.is_some() {
+            normal_response(ctx, command, Text::StayDisabled.into()).await;
+            return;
+        }
+
+        let channel_id = match get_channel_to_join(ctx, command) {
+            Ok(Some(id)) => {
+                if let Err(why) = join(ctx, guild_id, id).await {
+                    normal_response(ctx, command, why.into()).await;
+                    return;
+                }
+                id
+            },
+            Ok(None) => match get_bot_channel(ctx, guild_id) {
+                Some(id) => id,
+                None => {
+                    normal_response(ctx, command, Text::BotMustBeInVoiceChannel.into()).await;
+                    return;
+                }
+            },
+            Err(why) => {
+                normal_response(ctx, command, why.into()).await;
+                return;
+            }
+        };
+
+        ctx.data.write().await
+            .get_mut::<StayChannels>()
+            .expect("Guaranteed to exist in the typemap.")
+            .insert(guild_id, channel_id);
+
+        idle::cancel_leave(ctx, guild_id).await;
+
+        play_fallback(ctx, guild_id).await;
+
+        normal_response(ctx, command, Text::StayEnabled.into()).await;
+    }
+}
@@ -1,50 +1,142 @@
 mod commands;
 mod utils;
 
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-};
-
 use serenity::prelude::*;
 
-use songbird::SerenityInit;
+use songbird::{
+    SerenityInit,
+    Songbird,
+};
 
 use reqwest::Client as HttpClient;
 
-use clap::Parser;
+use tokio::time::timeout;
+
+use tracing::{
+    error,
+    info,
+};
 
 use crate::utils::{
     audio::{
         HttpKey,
         MetadataCache,
+        MetadataEntries,
         FileCache,
     },
+    admin,
     event_handler::Handler,
+    logging,
+    localization,
     local_files::get_audio_files,
     cli::Config,
+    play_log::PlayLog,
+    idle::IdleState,
+    stay::StayChannels,
+    queue_state::QueueSnapshots,
+    skip_votes::SkipVotes,
+    settings::Settings,
+    metrics::Metrics,
+    health::Health,
+    token::load_token,
+    status_server::{
+        self,
+        Endpoints,
+        ServerState,
+    },
+    shutdown::{
+        self,
+        Shutdown,
+        SHUTDOWN_TIMEOUT,
+    },
 };
 
 #[tokio::main]
 async fn main() {
-    let cli = Config::parse();
+    let cli = Config::load();
+
+    logging::init(&cli);
+
+    localization::init(cli.locales_directory.as_deref());
+
+    if let Some(command) = &cli.subcommand {
+        let ok = admin::run(&cli, command).await;
+        std::process::exit(if ok { 0 } else { 1 });
+    }
 
     let intents = GatewayIntents::GUILDS | GatewayIntents::GUILD_VOICE_STATES;
 
-    let token = read_to_string(&cli.token).expect("Can't read token");
+    let token = match load_token(&cli) {
+        Ok(token) => token,
+        Err(why) => {
+            error!("Invalid token: {why}");
+            std::process::exit(1);
+        }
+    };
+
+    let metrics_addr = cli.metrics_addr;
+    let health_addr = cli.health_addr;
+
+    let songbird = Songbird::serenity();
 
     let mut client =
         Client::builder(&token, intents)
         .event_handler(Handler)
-        .register_songbird()  
+        .register_songbird_with(songbird.clone())
         .type_map_insert::<HttpKey>(HttpClient::new())
-        .type_map_insert::<MetadataCache>(HashMap::new())
+        .type_map_insert::<MetadataCache>(MetadataEntries::default())
         .type_map_insert::<FileCache>(get_audio_files(&cli.audio_directory))
+        .type_map_insert::<PlayLog>(PlayLog::load(&cli.data_directory))
+        .type_map_insert::<IdleState>(IdleState::default())
+        .type_map_insert::<StayChannels>(StayChannels::load(&cli.data_directory))
+        .type_map_insert::<QueueSnapshots>(QueueSnapshots::load(&cli.data_directory))
+        .type_map_insert::<Shutdown>(Shutdown::default())
+        .type_map_insert::<SkipVotes>(SkipVotes::default())
+        .type_map_insert::<Settings>(Settings::load(&cli.data_directory, cli.guild_defaults.clone()))
+        .type_map_insert::<Metrics>(Metrics::default())
+        .type_map_insert::<Health>(Health::default())
         .type_map_insert::<Config>(cli)
         .await
         .expect("Err creating client");
 
+    let state = ServerState {
+        data: client.data.clone(),
+        manager: songbird,
+        shard_manager: client.shard_manager.clone(),
+    };
+
+    if let Some(addr) = metrics_addr {
+        let endpoints = Endpoints { metrics: true, health: health_addr == Some(addr) };
+        tokio::spawn(status_server::serve(addr, endpoints, state.clone()));
+    }
+
+    if let Some(addr) = health_addr && metrics_addr != Some(addr) {
+        let endpoints = Endpoints { metrics: false, health: true };
+        tokio::spawn(status_server::serve(addr, endpoints, state));
+    }
+
+    let http = client.http.clone();
+    let cache = client.cache.clone();
+    let data = client.data.clone();
+    let shard_manager = client.shard_manager.clone();
+
+    tokio::spawn(async move {
+        shutdown::wait_for_signal().await;
+
+        info!("Shutting down");
+
+        let graceful = async {
+            shutdown::cleanup(&http, &cache, &data).await;
+            shard_manager.shutdown_all().await;
+        };
+
+        if timeout(SHUTDOWN_TIMEOUT, graceful).await.is_err() {
+            error!("Graceful shutdown timed out");
+            std::process::exit(1);
+        }
+    });
+
     if let Err(why) = client.start().await {
-        eprintln!("Client error: {why:?}");
+        error!(error = ?why, "Client error");
     }
 }
@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    num::NonZeroU64,
+};
+
+use opus2::{
+    Application,
+    Channels,
+    Encoder,
+};
+
+use serenity::{
+    all::{
+        Command,
+        GuildId,
+    },
+    http::Http,
+};
+
+use super::{
+    cli::{
+        AdminCommand,
+        CommandsAction,
+        Config,
+    },
+    health::ytdlp_version,
+    local_files::read_audio_files,
+    registration,
+    response::format_duration,
+    token::load_token,
+};
+
+pub async fn run(config: &Config, command: &AdminCommand) -> bool {
+    match command {
+        AdminCommand::Check => check(config).await,
+        AdminCommand::Scan => scan(config),
+        AdminCommand::Commands { action } => match action {
+            CommandsAction::Sync { guild } => sync_commands(config, *guild).await,
+            CommandsAction::Clear { guild } => clear_commands(config, *guild).await,
+        },
+    }
+}
+
+async fn check(config: &Config) -> bool {
+    let mut ok = true;
+
+    match &config.config {
+        Some(path) => println!("config: ok ({})", path.display()),
+        None => println!("config: ok"),
+    }
+
+    match ytdlp_version(config).await {
+        Some(version) => println!("yt-dlp: ok ({version})"),
+        None => {
+            println!("yt-dlp: `{} --version` failed", config.ytdlp_path().display());
+            ok = false;
+        }
+    }
+
+    match load_token(config) {
+        Ok(_) => println!("token: ok"),
+        Err(why) => {
+            println!("token: {why}");
+            ok = false;
+        }
+    }
+
+    match Encoder::new(48000, Channels::Stereo, Application::Audio) {
+        Ok(_) => println!("libopus: ok ({})", opus2::version()),
+        Err(why) => {
+            println!("libopus: {why}");
+            ok = false;
+        }
+    }
+
+    ok
+}
+
+fn scan(config: &Config) -> bool {
+    let Some(dir) = &config.audio_directory else {
+        println!("No local audio directory configured, pass --local-audio");
+        return false;
+    };
+
+    let files = match read_audio_files(dir) {
+        Ok(files) => files,
+        Err(why) => {
+            println!("Failed to read {}: {why}", dir.display());
+            return false;
+        }
+    };
+
+    let mut names: HashMap<&str, &str> = HashMap::new();
+    let mut problems = 0;
+
+    for (name, metadata) in &files {
+        let filename = metadata.title.as_deref().unwrap_or_default();
+        let mut issues = Vec::new();
+
+        if metadata.duration.is_none() {
+            issues.push("unreadable audio properties".to_string());
+        }
+
+        if metadata.track.is_none() {
+            issues.push("missing title tag".to_string());
+        }
+
+        if metadata.artist.is_none() {
+            issues.push("missing artist tag".to_string());
+        }
+
+        if let Some(previous) = names.insert(name, filename) {
+            issues.push(format!("title \"{name}\" duplicates {previous}, only one of them is playable"));
+        }
+
+        if !issues.is_empty() {
+            problems += 1;
+            println!("{filename}: {}", issues.join(", "));
+        }
+    }
+
+    let total_duration: u64 = files.iter()
+        .filter_map(|(_, metadata)| metadata.duration.map(u64::from))
+        .sum();
+
+    println!(
+        "{} files, {} tracks, total duration {}, {problems} with problems",
+        files.len(),
+        names.len(),
+        format_duration(total_duration),
+    );
+
+    true
+}
+
+async fn sync_commands(config: &Config, guild: Option<NonZeroU64>) -> bool {
+    let Some(http) = http(config).await else {
+        return false;
+    };
+
+    match registration::sync_commands(&http, guild.map(GuildId::from)).await {
+        Ok(true) => {
+            println!("Commands registered");
+            true
+        }
+        Ok(false) => {
+            println!("Commands are up to date");
+            true
+        }
+        Err(why) => {
+            println!("Failed to register commands: {why}");
+            false
+        }
+    }
+}
+
+async fn clear_commands(config: &Config, guild: Option<NonZeroU64>) -> bool {
+    let Some(http) = http(config).await else {
+        return false;
+    };
+
+    let result = match guild {
+        Some(guild_id) => GuildId::from(guild_id).set_commands(&http, Vec::new()).await,
+        None => Command::set_global_commands(&http, Vec::new()).await,
+    };
+
+    match result {
+        Ok(_) => {
+            println!("Commands removed");
+            true
+        }
+        Err(why) => {
+            println!("Failed to remove commands: {why}");
+            false
+        }
+    }
+}
+
+async fn http(config: &Config) -> Option<Http> {
+    let token = match load_token(config) {
+        Ok(token) => token,
+        Err(why) => {
+            println!("Invalid token: {why}");
+            return None;
+        }
+    };
+
+    let http = Http::new(&token);
+
+    match http.get_current_application_info().await {
+        Ok(info) => {
+            http.set_application_id(info.id);
+            Some(http)
+        }
+        Err(why) => {
+            println!("Failed to fetch application info: {why}");
+            None
+        }
+    }
+}
@@ -8,8 +8,10 @@ use serenity::{
         },
         id::{
             ChannelId,
-            GuildId
+            GuildId,
+            UserId,
         },
+        user::User,
     },
     prelude::*
 };
@@ -20,7 +22,12 @@ use songbird::{
         File,
         Input,
     }, 
-    tracks::Track, 
+    tracks::{
+        PlayMode,
+        Track,
+        TrackHandle,
+    },
+    events::context_data::DisconnectReason,
     CoreEvent, 
     Event, 
     EventContext, 
@@ -29,25 +36,81 @@ use songbird::{
     TrackEvent
 };
 
-use reqwest::Client as HttpClient;
+use reqwest::{
+    Client as HttpClient,
+    Url,
+};
+
+use tokio::{
+    process::Command,
+    time::{
+        sleep,
+        timeout,
+    },
+};
 
 use std::{
-    collections::HashMap, 
-    ops::Deref, 
-    process::Command, 
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    io::ErrorKind,
+    process::Output,
     sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use tracing::{
+    error,
+    info,
+    warn,
 };
 
 use super::{
     response::{
         followup_response,
+        channel_embed,
         create_track_embed,
     },
     localization::Text,
     cli::Config,
+    idle,
+    play_log::{
+        self,
+        now,
+        PlayLog,
+        PlayRecord,
+    },
+    stay::{
+        self,
+        StayChannels,
+    },
+    queue_state::{
+        self,
+        QueueSnapshots,
+    },
+    shutdown::Shutdown,
+    content_policy::check_content,
+    metrics::metrics,
+    track_error::{
+        TrackError,
+        YtDlpError,
+    },
+    settings::{
+        command_locale,
+        guild_locale,
+        guild_settings,
+        GuildSettings,
+    },
 };
 
-use serde::Deserialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 #[derive(Deserialize, Clone)]
 pub struct Metadata {
@@ -59,6 +122,74 @@ pub struct Metadata {
     pub thumbnail: Option<String>,
     pub webpage_url: Option<String>,
     pub url: Option<String>,
+    pub webpage_url_domain: Option<String>,
+    pub is_live: Option<bool>,
+    pub age_limit: Option<u32>,
+}
+
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(15);
+
+const RATE_LIMIT_RETRIES: u32 = 2;
+
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(2);
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SourceKind {
+    #[default]
+    Remote,
+    Local,
+    Fallback,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Requester {
+    pub id: UserId,
+    pub name: String,
+    pub avatar: Option<String>,
+}
+
+impl Requester {
+    pub fn from_command(command: &CommandInteraction) -> Requester {
+        let name = command.member.as_ref()
+            .and_then(|member| member.nick.clone())
+            .unwrap_or_else(|| command.user.display_name().to_string());
+
+        Requester {
+            id: command.user.id,
+            name,
+            avatar: Some(command.user.face()),
+        }
+    }
+
+    pub fn from_user(user: &User) -> Requester {
+        Requester {
+            id: user.id,
+            name: user.display_name().to_string(),
+            avatar: Some(user.face()),
+        }
+    }
+}
+
+pub struct TrackData {
+    pub metadata: Metadata,
+    pub requester: Requester,
+    pub guild_id: GuildId,
+    pub requested_at: u64,
+    pub source: SourceKind,
+    pub command: Option<CommandInteraction>,
+}
+
+impl TrackData {
+    pub fn new(metadata: Metadata, requester: Requester, guild_id: GuildId, source: SourceKind) -> TrackData {
+        TrackData {
+            metadata,
+            requester,
+            guild_id,
+            requested_at: now(),
+            source,
+            command: None,
+        }
+    }
 }
 
 pub struct HttpKey;
@@ -70,7 +201,37 @@ impl TypeMapKey for HttpKey {
 pub struct MetadataCache;
 
 impl TypeMapKey for MetadataCache {
-    type Value = HashMap<String, Metadata>;
+    type Value = MetadataEntries;
+}
+
+/// Metadata keyed by query, evicting the oldest entries first.
+#[derive(Default)]
+pub struct MetadataEntries {
+    entries: HashMap<String, Metadata>,
+    order: VecDeque<String>,
+}
+
+impl MetadataEntries {
+    pub fn get(&self, key: &str) -> Option<&Metadata> {
+        self.entries.get(key)
+    }
+
+    pub fn insert(&mut self, key: String, metadata: Metadata, capacity: usize) {
+        if self.entries.contains_key(&key) {
+            self.order.retain(|existing| *existing != key);
+        } else {
+            while self.entries.len() >= capacity && let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        if capacity == 0 {
+            return;
+        }
+
+        self.order.push_back(key.clone());
+        self.entries.insert(key, metadata);
+    }
 }
 
 pub struct FileCache;
@@ -88,9 +249,14 @@ struct TrackStartNotifier {
 impl EventHandler for TrackStartNotifier {
     async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
         if let EventContext::Track([(_, handle)]) = ctx {
-            let data = handle.data::<(Metadata, Option<CommandInteraction>)>();
-            let (metadata, some_command) = data.deref();
-            if let Some(command) = some_command {
+            let data = handle.data::<TrackData>();
+            if let Some(command) = &data.command {
+                let settings = guild_settings(&self.ctx, self.guild_id).await;
+
+                if !settings.announces_now_playing() {
+                    return None;
+                }
+
                 let manager = songbird::get(&self.ctx)
                     .await
                     .expect("Songbird Voice client placed in at initialisation.")
@@ -103,9 +269,115 @@ impl EventHandler for TrackStartNotifier {
                         0
                     };
 
-                let embed = create_track_embed(metadata, queue_length, true, &command.locale);
+                let locale = guild_locale(&self.ctx.data, &self.ctx.cache, self.guild_id).await;
 
-                followup_response(&self.ctx, &command, embed).await;
+                let embed = create_track_embed(&data, queue_length, None, None, true, &locale);
+
+                match settings.announcement_channel {
+                    Some(channel_id) => channel_embed(&self.ctx.http, channel_id, embed).await,
+                    None => followup_response(&self.ctx, command, embed).await,
+                }
+            }
+        }
+        None
+    }
+}
+
+struct PlayLogNotifier {
+    ctx: Context,
+}
+
+#[async_trait]
+impl EventHandler for PlayLogNotifier {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        if let EventContext::Track(tracks) = ctx {
+            let mut records = Vec::new();
+
+            let path = {
+                let mut data = self.ctx.data.write().await;
+                let play_log = data.get_mut::<PlayLog>().expect("Guaranteed to exist in the typemap.");
+
+                for (state, handle) in tracks.iter() {
+                    let track_data = handle.data::<TrackData>();
+
+                    // Fallback tracks fill silence in stay mode, nobody requested them.
+                    if track_data.source == SourceKind::Fallback {
+                        continue;
+                    }
+
+                    if !state.playing.is_done() {
+                        play_log.start(handle);
+                        continue;
+                    }
+
+                    // Queued tracks that never started also end when the queue is stopped.
+                    let Some(started_at) = play_log.take_start(handle) else {
+                        continue;
+                    };
+
+                    if matches!(state.playing, PlayMode::Errored(_)) {
+                        continue;
+                    }
+
+                    let metadata = &track_data.metadata;
+
+                    let record = PlayRecord {
+                        title: metadata.track.clone().or(metadata.title.clone()),
+                        source: metadata.webpage_url.clone().or(metadata.url.clone()),
+                        requester: track_data.requester.id,
+                        requester_name: Some(track_data.requester.name.clone()),
+                        kind: track_data.source,
+                        guild_id: track_data.guild_id,
+                        started_at,
+                        listened: state.play_time.as_secs(),
+                        skipped: matches!(state.playing, PlayMode::Stop),
+                    };
+
+                    play_log.record(record.clone());
+                    records.push(record);
+                }
+
+                play_log.path()
+            };
+
+            if let Some(path) = path && !records.is_empty() {
+                let result = tokio::task::spawn_blocking(move || play_log::append(&path, &records)).await;
+
+                if let Err(why) = result {
+                    error!(error = ?why, "Play log writer panicked");
+                }
+            }
+        }
+
+        None
+    }
+}
+
+struct QueueSnapshotNotifier {
+    ctx: Context,
+    guild_id: GuildId,
+}
+
+#[async_trait]
+impl EventHandler for QueueSnapshotNotifier {
+    async fn act(&self, _: &EventContext<'_>) -> Option<Event> {
+        queue_state::save(&self.ctx.data, self.guild_id).await;
+        None
+    }
+}
+
+struct TrackErrorNotifier {
+    ctx: Context,
+    guild_id: GuildId,
+}
+
+#[async_trait]
+impl EventHandler for TrackErrorNotifier {
+    async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
+        if let EventContext::Track(tracks) = ctx {
+            for (state, _) in tracks.iter() {
+                warn!(guild_id = %self.guild_id, state = ?state.playing, "Track failed to play");
+                metrics(&self.ctx.data).await.track_start_failed();
             }
         }
         None
@@ -113,6 +385,7 @@ impl EventHandler for TrackStartNotifier {
 }
 
 struct TrackEndNotifier {
+    ctx: Context,
     manager: Arc<Songbird>,
     guild_id: GuildId,
 }
@@ -120,14 +393,22 @@ struct TrackEndNotifier {
 #[async_trait]
 impl EventHandler for TrackEndNotifier {
     async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
-        if let EventContext::Track(_) = ctx {
-            if let Some(handler_lock) = self.manager.get(self.guild_id) {
-                if handler_lock.lock().await.queue().is_empty() {
-                    if let Err(why) = self.manager.leave(self.guild_id).await {
-                        eprintln!("Failed to leave voice channel: {why:?}");
-                    }
+        if let EventContext::Track(tracks) = ctx {
+            for (state, handle) in tracks.iter() {
+                if handle.data::<TrackData>().source == SourceKind::Fallback {
+                    let failed = matches!(state.playing, PlayMode::Errored(_));
+
+                    self.ctx.data.write().await
+                        .get_mut::<StayChannels>()
+                        .expect("Guaranteed to exist in the typemap.")
+                        .fallback_ended(self.guild_id, failed);
                 }
             }
+
+            if let Some(handler_lock) = self.manager.get(self.guild_id)
+                && handler_lock.lock().await.queue().is_empty() {
+                idle::queue_ended(&self.ctx, self.guild_id).await;
+            }
         }
         
         None
@@ -135,6 +416,7 @@ impl EventHandler for TrackEndNotifier {
 }
 
 struct DriverDisconnectNotifier {
+    ctx: Context,
     manager: Arc<Songbird>,
     guild_id: GuildId,
 }
@@ -142,10 +424,20 @@ struct DriverDisconnectNotifier {
 #[async_trait]
 impl EventHandler for DriverDisconnectNotifier {
     async fn act(&self, ctx: &EventContext<'_>) -> Option<Event> {
-        if let EventContext::DriverDisconnect(_) = ctx {
+        if let EventContext::DriverDisconnect(data) = ctx {
             if let Err(why) = self.manager.remove(self.guild_id).await {
-                    eprintln!("Failed to remove voice handler: {why:?}");
+                error!(guild_id = %self.guild_id, error = ?why, "Failed to remove voice handler");
+            }
+
+            let requested = matches!(data.reason, None | Some(DisconnectReason::Requested));
+
+            if !requested {
+                if stay::stay_channel(&self.ctx, self.guild_id).await.is_some() {
+                    stay::schedule_rejoin(&self.ctx, self.guild_id);
+                } else {
+                    queue_state::remove(&self.ctx.data, self.guild_id).await;
                 }
+            }
         }
         None
     }
@@ -153,29 +445,52 @@ impl EventHandler for DriverDisconnectNotifier {
 
 pub fn get_channel_to_join(ctx: &Context, command: &CommandInteraction) -> Result<Option<ChannelId>, Text> {
     let guild_id = command.guild_id.ok_or(Text::CommandOnlyInGuild)?;
-    
-    let voice_states = guild_id.to_guild_cached(&ctx.cache)
-        .ok_or(Text::FailedToJoin)?
-        .voice_states
-        .clone();
 
-    if let Some(voice_state) = voice_states.get(&ctx.cache.current_user().id) {
-        if voice_state.channel_id.is_some() {
-            return Ok(None);
-        }
+    if get_bot_channel(ctx, guild_id).is_some() {
+        return Ok(None);
     }
 
-    let channel_id = voice_states
-        .get(&command.user.id)
+    get_user_channel(ctx, guild_id, command.user.id).map(Some)
+}
+
+/// Voice channel the user is currently in.
+pub fn get_user_channel(ctx: &Context, guild_id: GuildId, user_id: UserId) -> Result<ChannelId, Text> {
+    guild_id.to_guild_cached(&ctx.cache)
+        .ok_or(Text::FailedToJoin)?
+        .voice_states
+        .get(&user_id)
         .and_then(|voice_state| voice_state.channel_id)
-        .ok_or(Text::UserMustBeInVoiceChannel)?;
+        .ok_or(Text::UserMustBeInVoiceChannel)
+}
 
-    Ok(Some(channel_id))
+pub fn get_bot_channel(ctx: &Context, guild_id: GuildId) -> Option<ChannelId> {
+    let guild = guild_id.to_guild_cached(&ctx.cache)?;
+    guild.voice_states
+        .get(&ctx.cache.current_user().id)
+        .and_then(|voice_state| voice_state.channel_id)
 }
 
-pub async fn join(ctx: &Context, command: &CommandInteraction, channel_id: ChannelId) -> Result<(), Text> {
-    let guild_id = command.guild_id.ok_or(Text::CommandOnlyInGuild)?;
+pub fn get_listeners(ctx: &Context, guild_id: GuildId, channel_id: ChannelId) -> Vec<UserId> {
+    let guild = match guild_id.to_guild_cached(&ctx.cache) {
+        Some(guild) => guild,
+        None => return Vec::new(),
+    };
+
+    guild.voice_states.values()
+        .filter(|voice_state| voice_state.channel_id == Some(channel_id))
+        .filter(|voice_state| {
+            let is_bot = voice_state.member.as_ref()
+                .or(guild.members.get(&voice_state.user_id))
+                .map(|member| member.user.bot)
+                .or_else(|| ctx.cache.user(voice_state.user_id).map(|user| user.bot))
+                .unwrap_or(false);
+            !is_bot
+        })
+        .map(|voice_state| voice_state.user_id)
+        .collect()
+}
 
+pub async fn join(ctx: &Context, guild_id: GuildId, channel_id: ChannelId) -> Result<(), Text> {
     let manager = songbird::get(ctx)
         .await
         .expect("Songbird Voice client placed in at initialisation.")
@@ -185,24 +500,65 @@ pub async fn join(ctx: &Context, command: &CommandInteraction, channel_id: Chann
     let handle_lock = manager.join(guild_id, channel_id)
         .await
         .map_err(|why| {
-            eprintln!("Failed to join voice channel: {why:?}");
+            error!(%guild_id, %channel_id, error = ?why, "Failed to join voice channel");
             Text::FailedToJoin
         }
     )?;
 
+    info!(%guild_id, %channel_id, "Joined voice channel");
+
     let mut handle = handle_lock.lock().await;
 
     handle.add_global_event(
         Event::Track(TrackEvent::Play),
         TrackStartNotifier {
             ctx: ctx.clone(),
-            guild_id: guild_id,
+            guild_id,
+        }
+    );
+
+    handle.add_global_event(
+        Event::Track(TrackEvent::Play),
+        PlayLogNotifier {
+            ctx: ctx.clone(),
+        }
+    );
+
+    handle.add_global_event(
+        Event::Track(TrackEvent::End),
+        PlayLogNotifier {
+            ctx: ctx.clone(),
+        }
+    );
+
+    handle.add_global_event(
+        Event::Track(TrackEvent::Play),
+        QueueSnapshotNotifier {
+            ctx: ctx.clone(),
+            guild_id,
+        }
+    );
+
+    handle.add_global_event(
+        Event::Periodic(SNAPSHOT_INTERVAL, None),
+        QueueSnapshotNotifier {
+            ctx: ctx.clone(),
+            guild_id,
+        }
+    );
+
+    handle.add_global_event(
+        Event::Track(TrackEvent::Error),
+        TrackErrorNotifier {
+            ctx: ctx.clone(),
+            guild_id,
         }
     );
 
     handle.add_global_event(
         Event::Track(TrackEvent::End),
         TrackEndNotifier {
+            ctx: ctx.clone(),
             manager: manager.clone(),
             guild_id,
         }
@@ -211,7 +567,8 @@ pub async fn join(ctx: &Context, command: &CommandInteraction, channel_id: Chann
     handle.add_global_event(
         Event::Core(CoreEvent::DriverDisconnect),
         DriverDisconnectNotifier {
-            manager: manager,
+            ctx: ctx.clone(),
+            manager,
             guild_id,
         }
     );
@@ -219,7 +576,35 @@ pub async fn join(ctx: &Context, command: &CommandInteraction, channel_id: Chann
     Ok(())
 }
 
-pub async fn play(ctx: &Context, command: &CommandInteraction, track: Track, metadata: Metadata, add_to_queue: bool) -> Result<CreateEmbed, Text> {
+/// Checks content restrictions and queue limits of a requested track. Runs before joining,
+/// so a refused track doesn't pull the bot into a voice channel.
+pub async fn check_track(ctx: &Context, command: &CommandInteraction, guild_id: GuildId, metadata: &Metadata, source: SourceKind) -> Result<(), Text> {
+    let settings = guild_settings(ctx, guild_id).await;
+
+    if source == SourceKind::Remote {
+        let query = query_option(command).map_err(Text::from)?;
+
+        let data = ctx.data.read().await;
+        let config = data.get::<Config>()
+            .expect("Guaranteed to exist in the typemap.");
+
+        check_content(config, &settings, metadata, query)?;
+    }
+
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let queue = match manager.get(guild_id) {
+        Some(handler_lock) => handler_lock.lock().await.queue().current_queue(),
+        None => Vec::new(),
+    };
+
+    check_limits(&queue, &settings, command.user.id, metadata)
+}
+
+pub async fn play(ctx: &Context, command: &CommandInteraction, mut track: Track, metadata: Metadata, source: SourceKind, add_to_queue: bool) -> Result<CreateEmbed, Text> {
     let guild_id = command.guild_id.ok_or(Text::CommandOnlyInGuild)?;
 
     let manager = songbird::get(ctx)
@@ -234,38 +619,173 @@ pub async fn play(ctx: &Context, command: &CommandInteraction, track: Track, met
         }
     };
 
+    let settings = guild_settings(ctx, guild_id).await;
+
+    let config = ctx.data.read().await
+        .get::<Config>()
+        .cloned()
+        .expect("Guaranteed to exist in the typemap.");
+
+    // Requested tracks replace the fallback track of stay mode instead of waiting for it to end.
+    let replaces_fallback = handler_lock.lock().await.queue().current()
+        .is_some_and(|current| current.data::<TrackData>().source == SourceKind::Fallback);
+    let add_to_queue = add_to_queue && !replaces_fallback;
+
+    let mut data = TrackData::new(metadata, Requester::from_command(command), guild_id, source);
+    if add_to_queue {
+        data.command = Some(command.clone());
+    }
+
+    let data = Arc::new(data);
+    track.user_data = data.clone();
+    track.volume = f32::from(settings.volume(&config)) / 100.0;
+
+    idle::track_enqueued(ctx, guild_id).await;
+
+    ctx.data.write().await
+        .get_mut::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .set_text_channel(guild_id, command.channel_id);
+
     let mut handler = handler_lock.lock().await;
 
-    handler.enqueue(track).await;
+    let handle = handler.enqueue(track).await;
+
+    if replaces_fallback {
+        if let Some(fallback) = handler.queue().dequeue(0) {
+            let _ = fallback.stop();
+        }
+
+        if let Err(why) = handler.queue().resume() {
+            error!(%guild_id, error = ?why, "Failed to play track after fallback");
+        }
+    }
+
+    if settings.fair_queue {
+        handler.queue().modify_queue(|queue| {
+            if queue.len() <= 2 {
+                return;
+            }
+
+            let upcoming = queue.iter()
+                .skip(1)
+                .take(queue.len() - 2)
+                .map(|queued| queued.data::<TrackData>().requester.id)
+                .collect::<Vec<_>>();
+
+            let position = fair_position(&upcoming, command.user.id) + 1;
 
-    let embed = create_track_embed(&metadata, handler.queue().len() - 1, !add_to_queue, &command.locale);
+            if let Some(queued) = queue.pop_back() {
+                queue.insert(position, queued);
+            }
+        });
+    }
+
+    let queue = handler.queue().current_queue();
 
     drop(handler);
 
+    let (position, starts_in) = match add_to_queue {
+        true => (
+            queue.iter().position(|queued| queued.uuid() == handle.uuid()),
+            starts_in(&queue, &handle).await,
+        ),
+        false => (None, None),
+    };
+
+    let embed = create_track_embed(&data, queue.len() - 1, position, starts_in, !add_to_queue, &command_locale(&ctx.data, command).await);
+
+    queue_state::save(&ctx.data, guild_id).await;
+
     Ok(embed)
 }
 
-pub async fn process_query(ctx: &Context, command: &CommandInteraction) -> Result<(Track, Metadata), ()> {
+/// Seconds until the track starts playing, unknown if any track ahead of it has no duration.
+async fn starts_in(queue: &[TrackHandle], handle: &TrackHandle) -> Option<u64> {
+    let mut total = 0;
+
+    for (index, queued) in queue.iter().enumerate() {
+        if queued.uuid() == handle.uuid() {
+            return Some(total);
+        }
+
+        let duration = u64::from(queued.data::<TrackData>().metadata.duration?);
+
+        total += match index {
+            0 => duration.saturating_sub(queued.get_info().await.ok()?.position.as_secs()),
+            _ => duration,
+        };
+    }
+
+    None
+}
+
+fn check_limits(queue: &[TrackHandle], settings: &GuildSettings, requester: UserId, metadata: &Metadata) -> Result<(), Text> {
+    if let Some(max_duration) = settings.max_duration
+        && metadata.duration.is_some_and(|duration| duration > max_duration) {
+        return Err(Text::TrackTooLong(max_duration.into()));
+    }
+
+    let upcoming = queue.iter().skip(1);
+
+    if let Some(max_queue_length) = settings.max_queue_length
+        && upcoming.len() >= max_queue_length {
+        return Err(Text::QueueFull(max_queue_length));
+    }
+
+    if let Some(max_user_tracks) = settings.max_user_tracks
+        && upcoming.filter(|handle| handle.data::<TrackData>().requester.id == requester).count() >= max_user_tracks {
+        return Err(Text::UserTrackLimit(max_user_tracks));
+    }
+
+    Ok(())
+}
+
+fn fair_position(upcoming: &[UserId], requester: UserId) -> usize {
+    let round = upcoming.iter().filter(|user_id| **user_id == requester).count();
+
+    let mut occurrences: HashMap<UserId, usize> = HashMap::new();
+    let mut position = 0;
+
+    for (index, user_id) in upcoming.iter().enumerate() {
+        let occurrence = occurrences.entry(*user_id).or_insert(0);
+        if *occurrence <= round {
+            position = index + 1;
+        }
+        *occurrence += 1;
+    }
+
+    position
+}
+
+pub async fn process_query(ctx: &Context, command: &CommandInteraction) -> Result<(Track, Metadata), TrackError> {
+    let query = query_option(command)?;
+
+    resolve_query(ctx, query).await.inspect_err(|why| {
+        warn!(query = %query, error = %why, "Failed to resolve query");
+    })
+}
+
+fn query_option(command: &CommandInteraction) -> Result<&String, TrackError> {
     let value = match &command.data.options.first() {
         Some(option) => &option.value,
         None => {
-            eprintln!("No options found in {command:?}");
-            return Err(());
+            warn!("No options found in {command:?}");
+            return Err(TrackError::MissingQuery);
         }
     };
 
-    let query = match value {
-        CommandDataOptionValue::String(query) => query,
+    match value {
+        CommandDataOptionValue::String(query) => Ok(query),
         _ => {
-            eprintln!("Expected a string query, got: {value:?}");
-            return Err(());
+            warn!("Expected a string query, got: {value:?}");
+            Err(TrackError::MissingQuery)
         },
-    };
+    }
+}
 
-    let metadata = match fetch_metadata(ctx, query).await {
-        Ok(metadata) => metadata,
-        Err(_) => return Err(()),
-    };
+pub async fn resolve_query(ctx: &Context, query: &String) -> Result<(Track, Metadata), TrackError> {
+    let metadata = fetch_metadata(ctx, query).await?;
 
     let http_client = {
         let data = ctx.data.read().await;
@@ -277,15 +797,14 @@ pub async fn process_query(ctx: &Context, command: &CommandInteraction) -> Resul
     let source = if let Some(ref url) = metadata.url {
             HttpRequest::new(http_client, url.clone())
         } else {
-            eprintln!("No URL found in metadata: {:?}", metadata.url);
-            return Err(());
+            return Err(TrackError::StreamOpen("no URL found in metadata".to_string()));
         };
 
     let input = match Input::from(source).make_live_async().await {
         Ok(input) => input,
         Err(why) => {
-            eprintln!("Failed to create live input: {why:?}");
-            return Err(());
+            metrics(&ctx.data).await.track_start_failed();
+            return Err(TrackError::StreamOpen(why.to_string()));
         }
     };
 
@@ -294,7 +813,7 @@ pub async fn process_query(ctx: &Context, command: &CommandInteraction) -> Resul
     Ok((track, metadata))
 }
 
-async fn fetch_metadata(ctx: &Context, query: &String) -> Result<Metadata, ()> {
+async fn fetch_metadata(ctx: &Context, query: &String) -> Result<Metadata, TrackError> {
     let http_client: HttpClient = {
         let data = ctx.data.read().await;
         data.get::<HttpKey>()
@@ -302,45 +821,47 @@ async fn fetch_metadata(ctx: &Context, query: &String) -> Result<Metadata, ()> {
             .expect("Guaranteed to exist in the typemap.")
     };
 
-    if let Some(metadata) = ctx.data.read().await
+    let cached = ctx.data.read().await
         .get::<MetadataCache>()
         .expect("Guaranteed to exist in the typemap.")
-        .get(query) {
-        let response = match http_client
+        .get(query)
+        .cloned();
+
+    let metrics = metrics(&ctx.data).await;
+
+    metrics.metadata_cache(cached.is_some());
+
+    if let Some(metadata) = cached {
+        match http_client
             .head(metadata.url.as_deref().unwrap_or(""))
             .send()
             .await {
-            Ok(response) => response,
-            Err(why) => {
-                eprintln!("Failed to send HEAD request: {why:?}");
-                return Err(());
-            }
-        };
-
-        if response.status().is_success() {
-            return Ok(metadata.clone());
+            Ok(response) if response.status().is_success() => return Ok(metadata),
+            Ok(_) => {},
+            Err(why) => warn!(query = %query, error = ?why, "Failed to send HEAD request for cached metadata"),
         }
     }
 
-    let metadata = match fetch_metadata_ytdlp(ctx, query).await {
-        Ok(metadata) => metadata,
-        Err(_) => return Err(()),
-    };
+    let metadata = fetch_metadata_ytdlp(ctx, query).await?;
     
     let mut data = ctx.data.write().await;
 
+    let cache_size = data.get::<Config>()
+        .expect("Guaranteed to exist in the typemap.")
+        .metadata_cache_size;
+
     let cache = data.get_mut::<MetadataCache>().expect("Guaranteed to exist in the typemap.");
 
-    cache.insert(query.to_string(), metadata.clone());
+    cache.insert(query.to_string(), metadata.clone(), cache_size);
 
     if let Some(ref webpage_url) = metadata.webpage_url {
-        cache.insert(webpage_url.clone(), metadata.clone());
+        cache.insert(webpage_url.clone(), metadata.clone(), cache_size);
     }
     
     Ok(metadata)
 }
 
-async fn fetch_metadata_ytdlp(ctx: &Context, query: &String) -> Result<Metadata, ()> {
+async fn fetch_metadata_ytdlp(ctx: &Context, query: &String) -> Result<Metadata, TrackError> {
     let ytdlp_query = if query.contains("/") {
         query.to_string()
         } else {
@@ -354,111 +875,138 @@ async fn fetch_metadata_ytdlp(ctx: &Context, query: &String) -> Result<Metadata,
             .expect("Guaranteed to exist in the typemap.")
     };
 
-    let mut ytdlp_output = match Command::new(config.yt_dlp.clone())
-        .args(["--format", 
-            "bestaudio/best",
-            "--ignore-config",
-            "--no-playlist",
-            "--no-download",
-            "--dump-json",
-            ytdlp_query.as_str()
-            ])
-        .output() {
-        Ok(output) => output,
-        Err(why) => {
-            eprintln!("Failed to run yt-dlp: {why:?}");
-            return Err(());
-        }
-    };
+    let mut cookies = None;
+    let mut retries = 0;
 
-    if !ytdlp_output.status.success() {
-        let stderr= match str::from_utf8(&ytdlp_output.stdout) {
-            Ok(s) => s,
-            Err(_) => "",
-        };
-        if stderr.contains("cookies") && let Some(cookies_path) = config.cookies {
-            ytdlp_output = match Command::new(config.yt_dlp)
-                .args(["--format", 
-                    "bestaudio/best",
-                    "--cookies",
-                    cookies_path.as_str(),
-                    "--ignore-config",
-                    "--no-playlist",
-                    "--no-download",
-                    "--dump-json",
-                    ytdlp_query.as_str()
-                    ])
-                .output() {
-                Ok(output) => output,
-                Err(why) => {
-                    eprintln!("Failed to run yt-dlp: {why:?}");
-                    return Err(());
-                }
-            };
+    let ytdlp_output = loop {
+        let output = run_ytdlp(ctx, &config, &ytdlp_query, cookies).await?;
+
+        if output.status.success() {
+            break output;
         }
-    }
 
-    if !ytdlp_output.status.success() {
-        eprintln!("yt-dlp returned a non-zero exit code: {:?}", ytdlp_output);
-        return Err(());
-    }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let error = YtDlpError::classify(&stderr);
+        warn!(query = %ytdlp_query, status = %output.status, category = %error, stderr = stderr.trim(), "yt-dlp failed");
 
-    let metadata = match serde_json::from_slice(&ytdlp_output.stdout) {
-        Ok(metadata) => metadata,
-        Err(why) => {
-            eprintln!("Failed to parse yt-dlp output: {why:?}");
-            return Err(());
+        if error.needs_cookies() && cookies.is_none() && let Some(cookies_path) = &config.cookies {
+            cookies = Some(cookies_path.as_str());
+        } else if error == YtDlpError::RateLimited && retries < RATE_LIMIT_RETRIES {
+            sleep(RATE_LIMIT_BACKOFF * 2u32.pow(retries)).await;
+            retries += 1;
+        } else {
+            metrics(&ctx.data).await.ytdlp_failed(error.category());
+            return Err(TrackError::YtDlp(error));
         }
     };
 
-    Ok(metadata)
+    serde_json::from_slice(&ytdlp_output.stdout)
+        .map_err(|why| TrackError::Parse(why.to_string()))
 }
 
-pub async fn process_local_query(ctx: &Context, command: &CommandInteraction) -> Result<(Track, Metadata), ()> {
-    let value = match &command.data.options.first() {
-        Some(option) => &option.value,
-        None => {
-            eprintln!("No options found in {command:?}");
-            return Err(());
-        }
-    };
+pub async fn process_local_query(ctx: &Context, command: &CommandInteraction) -> Result<(Track, Metadata), TrackError> {
+    let query = query_option(command)?;
 
-    let query = match value {
-        CommandDataOptionValue::String(query) => query,
-        _ => {
-            eprintln!("Expected a string query, got: {value:?}");
-            return Err(());
-        },
-    };
+    let metadata: Metadata = ctx.data.read().await
+        .get::<FileCache>()
+        .expect("Guaranteed to exist in the typemap.")
+        .get(query)
+        .cloned()
+        .ok_or(TrackError::NoSuchFile)?;
 
-    let metadata: Metadata = {
-        let data = ctx.data.read().await;
-        let cache = data.get::<FileCache>()
-            .cloned()
-            .expect("Guaranteed to exist in the typemap.");
-        if let Some(metadata) = cache.get(query) {
-            metadata.clone()
-        } else {
-            return Err(());
-        }
-    };
+    let track = local_track(&metadata).await.inspect_err(|why| {
+        warn!(query = %query, error = %why, "Failed to open local file");
+    })?;
 
+    Ok((track, metadata))
+}
+
+pub async fn local_track(metadata: &Metadata) -> Result<Track, TrackError> {
     let source = if let Some(ref url) = metadata.url {
             File::new(url.clone())
         } else {
-            eprintln!("No URL found in metadata: {:?}", metadata.url);
-            return Err(());
+            return Err(TrackError::NoSuchFile);
         };
 
-    let input = match Input::from(source).make_live_async().await {
-        Ok(input) => input,
-        Err(why) => {
-            eprintln!("Failed to create live input: {why:?}");
-            return Err(());
-        }
+    let input = Input::from(source).make_live_async().await
+        .map_err(|why| TrackError::StreamOpen(why.to_string()))?;
+
+    Ok(Track::from(input))
+}
+
+async fn run_ytdlp(ctx: &Context, config: &Config, query: &str, cookies: Option<&str>) -> Result<Output, TrackError> {
+    let mut command = Command::new(config.ytdlp_path());
+
+    // Searches parse as URLs with a "ytsearch" scheme and no host.
+    let domain = match Url::parse(query) {
+        _ if query.starts_with("ytsearch:") => Some("youtube.com".to_string()),
+        Ok(url) => url.host_str().map(|host| host.to_lowercase()),
+        Err(_) => Some("youtube.com".to_string()),
     };
 
-    let track = Track::from(input);
+    command.args(["--format", config.ytdlp_format(domain.as_deref())]);
 
-    Ok((track, metadata))
-}
\ No newline at end of file
+    if let Some(cookies_path) = cookies {
+        command.args(["--cookies", cookies_path]);
+    }
+
+    if let Some(proxy) = &config.ytdlp_proxy {
+        command.args(["--proxy", proxy]);
+    }
+
+    if let Some(source_address) = config.ytdlp_source_address {
+        command.args(["--source-address", &source_address.to_string()]);
+    }
+
+    if let Some(seconds) = config.ytdlp_sleep_requests {
+        command.args(["--sleep-requests", &seconds.to_string()]);
+    }
+
+    command
+        .args([
+            "--ignore-config",
+            "--no-playlist",
+            "--no-download",
+            "--dump-json",
+        ])
+        .args(&config.ytdlp_args)
+        .args(["--", query])
+        .kill_on_drop(true);
+
+    let mut shutdown = ctx.data.read().await
+        .get::<Shutdown>()
+        .expect("Guaranteed to exist in the typemap.")
+        .subscribe();
+
+    let started = Instant::now();
+
+    let result = tokio::select! {
+        output = timeout(Duration::from_secs(config.ytdlp_timeout), command.output()) => match output {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(why)) if why.kind() == ErrorKind::NotFound => Err(TrackError::YtDlpMissing),
+            Ok(Err(why)) => Err(TrackError::YtDlp(YtDlpError::Other(why.to_string()))),
+            Err(_) => Err(TrackError::Timeout),
+        },
+        _ = shutdown.wait_for(|shutting_down| *shutting_down) => Err(TrackError::ShuttingDown),
+    };
+
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    let metrics = metrics(&ctx.data).await;
+
+    metrics.ytdlp_finished(started.elapsed());
+
+    match &result {
+        Ok(_) => {},
+        Err(TrackError::Timeout) => metrics.ytdlp_failed("timeout"),
+        Err(TrackError::YtDlpMissing) => metrics.ytdlp_failed("missing"),
+        Err(_) => metrics.ytdlp_failed("other"),
+    }
+
+    match &result {
+        Ok(output) => info!(query, cookies = cookies.is_some(), duration_ms, status = %output.status, "yt-dlp finished"),
+        Err(why) => warn!(query, cookies = cookies.is_some(), duration_ms, error = %why, "yt-dlp did not finish"),
+    }
+
+    result
+}
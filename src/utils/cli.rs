@@ -1,51 +1,505 @@
-use clap::Parser;
+use clap::{
+    error::ErrorKind,
+    parser::ValueSource,
+    ArgMatches,
+    CommandFactory,
+    Parser,
+    Subcommand,
+    ValueEnum,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use serenity::prelude::TypeMapKey;
-use std::path::PathBuf;
+use tracing_subscriber::EnvFilter;
+use toml::{
+    Table,
+    Value,
+};
+use std::{
+    ffi::OsString,
+    fs::read_to_string,
+    net::{
+        IpAddr,
+        SocketAddr,
+    },
+    num::NonZeroU64,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use super::{
+    content_policy::matches_domain,
+    settings::{
+        GuildDefaults,
+        GuildSettings,
+    },
+};
 
 #[derive(Parser, Clone)]
-#[command(version, about)]
+#[command(version, about, args_override_self = true)]
 pub struct Config {
+    #[arg(
+        long,
+        value_name = "FILE_PATH",
+        help = "TOML config file, its values are overridden by environment variables and arguments",
+        env = "MUSICBOT_CONFIG",
+        value_parser = validate_file_path,
+    )]
+    pub config: Option<PathBuf>,
+
     #[arg(
         short, 
         long, 
         value_name = "EXECUTABLE_PATH", 
-        help = "Path to the yt-dlp executable",
+        help = "Path to the yt-dlp executable, required unless running scan or commands",
+        env = "MUSICBOT_YT_DLP",
         value_parser = validate_executable_path,
     )]
-    pub yt_dlp: PathBuf,
+    pub yt_dlp: Option<PathBuf>,
 
     #[arg(
         short, 
         long, 
         value_name = "TOKEN_PATH", 
-        help = "Path to discord bot token",
+        help = "Path to discord bot token, DISCORD_TOKEN or the `discord-token` systemd credential are used if not set",
+        env = "MUSICBOT_TOKEN",
         value_parser = validate_file_path,
     )]
-    pub token: PathBuf,
+    pub token: Option<PathBuf>,
 
     #[arg(
         short,
         long = "local-audio",
         value_name = "DIRECTORY_PATH",
         help = "Directory containing local audio files",
+        env = "MUSICBOT_LOCAL_AUDIO",
         value_parser = validate_directory_path,
     )]
     pub audio_directory: Option<PathBuf>,
 
+    #[arg(
+        long = "locales",
+        value_name = "DIRECTORY_PATH",
+        help = "Directory with <locale>.ftl files overriding built-in translations or adding new languages",
+        env = "MUSICBOT_LOCALES",
+        value_parser = validate_directory_path,
+    )]
+    pub locales_directory: Option<PathBuf>,
+
     #[arg(
         short,
         long,
         value_name = "FILE_PATH",
         help = "Path to cookies file for yt-dlp authentication",
+        env = "MUSICBOT_COOKIES",
         value_parser = validate_file_path_string,
     )]
     pub cookies: Option<String>,
+
+    #[arg(
+        short,
+        long = "data-directory",
+        value_name = "DIRECTORY_PATH",
+        help = "Directory for persistent bot data, such as the play log",
+        env = "MUSICBOT_DATA_DIRECTORY",
+        value_parser = validate_directory_path,
+    )]
+    pub data_directory: Option<PathBuf>,
+
+    #[arg(
+        long = "alone-timeout",
+        value_name = "SECONDS",
+        help = "Seconds to wait before leaving a voice channel with no listeners",
+        env = "MUSICBOT_ALONE_TIMEOUT",
+        default_value_t = 60,
+    )]
+    pub alone_timeout: u64,
+
+    #[arg(
+        long = "idle-timeout",
+        value_name = "MINUTES",
+        help = "Minutes to stay in a voice channel after the queue ends, or `never` to stay connected",
+        env = "MUSICBOT_IDLE_TIMEOUT",
+        default_value = "0",
+        value_parser = parse_idle_timeout,
+    )]
+    pub idle_timeout: IdleTimeout,
+
+    #[arg(
+        long = "fallback-playlist",
+        value_name = "FILE_PATH",
+        help = "File with names of local audio files to play in stay mode when the queue ends",
+        env = "MUSICBOT_FALLBACK_PLAYLIST",
+        value_parser = validate_file_path,
+    )]
+    pub fallback_playlist: Option<PathBuf>,
+
+    #[arg(
+        long = "vote-skip-threshold",
+        value_name = "FRACTION",
+        help = "Fraction of listeners that must vote to skip a track, e.g. 0.5; anyone can skip if not set",
+        env = "MUSICBOT_VOTE_SKIP_THRESHOLD",
+        value_parser = parse_fraction,
+    )]
+    pub vote_skip_threshold: Option<f64>,
+
+    #[arg(
+        long = "allowed-domains",
+        value_name = "DOMAINS",
+        help = "Comma-separated domains tracks can be played from; all domains are allowed if not set",
+        env = "MUSICBOT_ALLOWED_DOMAINS",
+        value_delimiter = ',',
+    )]
+    pub allowed_domains: Vec<String>,
+
+    #[arg(
+        long = "blocked-domains",
+        value_name = "DOMAINS",
+        help = "Comma-separated domains tracks can't be played from",
+        env = "MUSICBOT_BLOCKED_DOMAINS",
+        value_delimiter = ',',
+    )]
+    pub blocked_domains: Vec<String>,
+
+    #[arg(
+        long = "blocked-keywords",
+        value_name = "KEYWORDS",
+        help = "Comma-separated keywords that can't appear in track titles",
+        env = "MUSICBOT_BLOCKED_KEYWORDS",
+        value_delimiter = ',',
+    )]
+    pub blocked_keywords: Vec<String>,
+
+    #[arg(
+        long = "blocked-uploaders",
+        value_name = "UPLOADERS",
+        help = "Comma-separated uploaders or artists whose tracks can't be played",
+        env = "MUSICBOT_BLOCKED_UPLOADERS",
+        value_delimiter = ',',
+    )]
+    pub blocked_uploaders: Vec<String>,
+
+    #[arg(
+        long = "max-duration",
+        value_name = "MINUTES",
+        help = "Maximum duration of tracks from the internet in minutes",
+        env = "MUSICBOT_MAX_DURATION",
+    )]
+    pub max_duration: Option<u32>,
+
+    #[arg(
+        long = "refuse-livestreams",
+        help = "Refuse to play livestreams",
+        env = "MUSICBOT_REFUSE_LIVESTREAMS",
+    )]
+    pub refuse_livestreams: bool,
+
+    #[arg(
+        long = "refuse-age-restricted",
+        help = "Refuse to play age-restricted content",
+        env = "MUSICBOT_REFUSE_AGE_RESTRICTED",
+    )]
+    pub refuse_age_restricted: bool,
+
+    #[arg(
+        long = "log-filter",
+        value_name = "FILTER",
+        env = "MUSICBOT_LOG",
+        help = "Log filter directives, e.g. `info` or `warn,musicbot=debug`",
+        default_value = "warn,musicbot=info",
+        value_parser = validate_log_filter,
+    )]
+    pub log_filter: String,
+
+    #[arg(
+        long = "log-format",
+        value_name = "FORMAT",
+        env = "MUSICBOT_LOG_FORMAT",
+        help = "Log output format",
+        default_value = "text",
+    )]
+    pub log_format: LogFormat,
+
+    #[arg(
+        long = "metrics-addr",
+        value_name = "ADDRESS",
+        help = "Address to serve Prometheus metrics on, e.g. 127.0.0.1:9100",
+        env = "MUSICBOT_METRICS_ADDR",
+    )]
+    pub metrics_addr: Option<SocketAddr>,
+
+    #[arg(
+        long = "health-addr",
+        value_name = "ADDRESS",
+        help = "Address to serve /healthz and /readyz on, can be the same as --metrics-addr",
+        env = "MUSICBOT_HEALTH_ADDR",
+    )]
+    pub health_addr: Option<SocketAddr>,
+
+    #[arg(
+        long = "ytdlp-timeout",
+        value_name = "SECONDS",
+        help = "Seconds to wait for yt-dlp before giving up",
+        env = "MUSICBOT_YTDLP_TIMEOUT",
+        default_value_t = 60,
+    )]
+    pub ytdlp_timeout: u64,
+
+    #[arg(
+        long = "metadata-cache-size",
+        value_name = "ENTRIES",
+        help = "Maximum number of cached track metadata entries",
+        env = "MUSICBOT_METADATA_CACHE_SIZE",
+        default_value_t = 1000,
+    )]
+    pub metadata_cache_size: usize,
+
+    #[arg(
+        long = "default-volume",
+        value_name = "PERCENT",
+        help = "Volume of played tracks in percent",
+        env = "MUSICBOT_DEFAULT_VOLUME",
+        default_value_t = 100,
+        value_parser = clap::value_parser!(u8).range(0..=200),
+    )]
+    pub default_volume: u8,
+
+    #[arg(
+        long = "ytdlp-format",
+        value_name = "FORMAT",
+        help = "yt-dlp format selector",
+        env = "MUSICBOT_YTDLP_FORMAT",
+        default_value = "bestaudio/best",
+    )]
+    pub ytdlp_format: String,
+
+    #[arg(
+        long = "ytdlp-extractor-format",
+        value_name = "DOMAIN=FORMAT",
+        help = "yt-dlp format selector for tracks from given domain, e.g. soundcloud.com=http_mp3_1_0, can be repeated",
+        env = "MUSICBOT_YTDLP_EXTRACTOR_FORMAT",
+        value_parser = parse_extractor_format,
+    )]
+    pub ytdlp_extractor_formats: Vec<(String, String)>,
+
+    #[arg(
+        long = "ytdlp-proxy",
+        value_name = "URL",
+        help = "Proxy used by yt-dlp",
+        env = "MUSICBOT_YTDLP_PROXY",
+    )]
+    pub ytdlp_proxy: Option<String>,
+
+    #[arg(
+        long = "ytdlp-source-address",
+        value_name = "IP",
+        help = "Client-side IP address yt-dlp binds to",
+        env = "MUSICBOT_YTDLP_SOURCE_ADDRESS",
+    )]
+    pub ytdlp_source_address: Option<IpAddr>,
+
+    #[arg(
+        long = "ytdlp-sleep-requests",
+        value_name = "SECONDS",
+        help = "Seconds yt-dlp sleeps between requests during extraction",
+        env = "MUSICBOT_YTDLP_SLEEP_REQUESTS",
+    )]
+    pub ytdlp_sleep_requests: Option<f64>,
+
+    #[arg(
+        long = "ytdlp-arg",
+        value_name = "ARGUMENT",
+        help = "Additional argument passed to yt-dlp, can be repeated",
+        env = "MUSICBOT_YTDLP_ARG",
+        allow_hyphen_values = true,
+    )]
+    pub ytdlp_args: Vec<String>,
+
+    #[arg(
+        long = "dev-guild",
+        value_name = "GUILD_ID",
+        help = "Register commands only in given guild, they update instantly unlike global ones",
+        env = "MUSICBOT_DEV_GUILD",
+    )]
+    pub dev_guild: Option<NonZeroU64>,
+
+    #[arg(skip)]
+    pub guild_defaults: GuildSettings,
+
+    #[command(subcommand)]
+    pub subcommand: Option<AdminCommand>,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AdminCommand {
+    #[command(about = "Validate config, yt-dlp, token and libopus, then exit")]
+    Check,
+
+    #[command(about = "Index the local audio library and report tag problems, then exit")]
+    Scan,
+
+    #[command(about = "Manage slash command registration, then exit")]
+    Commands {
+        #[command(subcommand)]
+        action: CommandsAction,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum CommandsAction {
+    #[command(about = "Register current slash commands")]
+    Sync {
+        #[arg(long, value_name = "ID", help = "Register in given guild instead of globally")]
+        guild: Option<NonZeroU64>,
+    },
+
+    #[command(about = "Remove all registered slash commands")]
+    Clear {
+        #[arg(long, value_name = "ID", help = "Remove from given guild instead of globally")]
+        guild: Option<NonZeroU64>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(try_from = "String", into = "String")]
+pub enum IdleTimeout {
+    Immediate,
+    After(Duration),
+    Never,
+}
+
+impl TryFrom<String> for IdleTimeout {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse_idle_timeout(&value)
+    }
+}
+
+impl From<IdleTimeout> for String {
+    fn from(timeout: IdleTimeout) -> Self {
+        match timeout {
+            IdleTimeout::Immediate => "immediate".to_string(),
+            IdleTimeout::After(delay) => (delay.as_secs() / 60).to_string(),
+            IdleTimeout::Never => "never".to_string(),
+        }
+    }
 }
 
 impl TypeMapKey for Config {
     type Value = Config;
 }
 
+impl Config {
+    pub fn load() -> Config {
+        let args = std::env::args_os().collect::<Vec<_>>();
+
+        let matches = Config::command()
+            .ignore_errors(true)
+            .get_matches_from(&args);
+
+        let (file_args, guild_defaults) = match matches.get_one::<PathBuf>("config") {
+            Some(path) => match read_config_file(path, &matches) {
+                Ok(values) => values,
+                Err(why) => Config::command()
+                    .error(ErrorKind::ValueValidation, format!("invalid config file '{}': {}", path.display(), why))
+                    .exit(),
+            },
+            None => (Vec::new(), GuildSettings::default()),
+        };
+
+        let mut config = Config::parse_from(args.iter().take(1).cloned().chain(file_args).chain(args.iter().skip(1).cloned()));
+        config.guild_defaults = guild_defaults;
+
+        // Only the bot itself and `check` run yt-dlp.
+        if config.yt_dlp.is_none() && matches!(config.subcommand, None | Some(AdminCommand::Check)) {
+            Config::command()
+                .error(ErrorKind::MissingRequiredArgument, "the following required argument was not provided: --yt-dlp <EXECUTABLE_PATH>")
+                .exit();
+        }
+
+        config
+    }
+
+    /// Path to yt-dlp, checked in [`Config::load`] whenever it's needed.
+    pub fn ytdlp_path(&self) -> &Path {
+        self.yt_dlp.as_deref().expect("Required unless running scan or commands.")
+    }
+
+    pub fn ytdlp_format(&self, domain: Option<&str>) -> &str {
+        domain
+            .and_then(|domain| self.ytdlp_extractor_formats.iter()
+                .find(|(pattern, _)| matches_domain(domain, pattern)))
+            .map(|(_, format)| format.as_str())
+            .unwrap_or(&self.ytdlp_format)
+    }
+}
+
+fn read_config_file(path: &Path, matches: &ArgMatches) -> Result<(Vec<OsString>, GuildSettings), String> {
+    let content = read_to_string(path).map_err(|why| why.to_string())?;
+    let mut table = content.parse::<Table>().map_err(|why| why.to_string())?;
+
+    let locales_directory = matches.get_one::<PathBuf>("locales_directory")
+        .cloned()
+        .or_else(|| table.get("locales").and_then(Value::as_str).map(PathBuf::from));
+
+    let guild_defaults = match table.remove("guild_defaults") {
+        Some(value) => value.try_into::<GuildDefaults>()
+            .map_err(|why| why.to_string())
+            .and_then(|defaults| defaults.into_settings(locales_directory.as_deref()))
+            .map_err(|why| format!("guild_defaults: {why}"))?,
+        None => GuildSettings::default(),
+    };
+
+    let command = Config::command();
+    let mut args = Vec::new();
+
+    for (key, value) in table {
+        let name = key.replace('_', "-");
+
+        let arg = command.get_arguments()
+            .find(|arg| arg.get_long() == Some(name.as_str()) && arg.get_id() != "config")
+            .ok_or(format!("unknown option '{key}'"))?;
+
+        let overridden = matches!(
+            matches.value_source(arg.get_id().as_str()),
+            Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+        );
+
+        if overridden {
+            continue;
+        }
+
+        let values = match value {
+            Value::Array(values) => values,
+            value => vec![value],
+        };
+
+        for value in values {
+            match value {
+                Value::Boolean(true) if !arg.get_action().takes_values() => args.push(format!("--{name}").into()),
+                Value::Boolean(false) if !arg.get_action().takes_values() => {},
+                Value::String(value) => args.push(format!("--{name}={value}").into()),
+                Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => args.push(format!("--{name}={value}").into()),
+                _ => return Err(format!("unsupported value for '{key}'")),
+            }
+        }
+    }
+
+    Ok((args, guild_defaults))
+}
+
 fn validate_executable_path(path: &str) -> Result<PathBuf, String> {
     let pb = PathBuf::from(path);
     if !pb.exists() {
@@ -93,3 +547,34 @@ fn validate_file_path_string(path: &str) -> Result<String, String> {
     }
 }
 
+pub fn parse_idle_timeout(value: &str) -> Result<IdleTimeout, String> {
+    match value {
+        "never" => Ok(IdleTimeout::Never),
+        "0" | "immediate" => Ok(IdleTimeout::Immediate),
+        minutes => match minutes.parse::<u64>().ok().and_then(|minutes| minutes.checked_mul(60)) {
+            Some(seconds) => Ok(IdleTimeout::After(Duration::from_secs(seconds))),
+            None => Err(format!("'{}' is not a number of minutes, `immediate` or `never`", value)),
+        },
+    }
+}
+
+fn validate_log_filter(value: &str) -> Result<String, String> {
+    match EnvFilter::try_new(value) {
+        Ok(_) => Ok(value.to_string()),
+        Err(why) => Err(format!("'{}' is not a valid log filter: {}", value, why)),
+    }
+}
+
+fn parse_extractor_format(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((domain, format)) if !domain.is_empty() && !format.is_empty() => Ok((domain.to_string(), format.to_string())),
+        _ => Err(format!("'{}' is not in DOMAIN=FORMAT form", value)),
+    }
+}
+
+fn parse_fraction(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(fraction) if fraction > 0.0 && fraction <= 1.0 => Ok(fraction),
+        _ => Err(format!("'{}' is not a number between 0 and 1", value)),
+    }
+}
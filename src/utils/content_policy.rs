@@ -0,0 +1,86 @@
+use reqwest::Url;
+
+use super::{
+    audio::Metadata,
+    cli::Config,
+    localization::Text,
+    settings::GuildSettings,
+};
+
+pub fn check_content(config: &Config, settings: &GuildSettings, metadata: &Metadata, query: &str) -> Result<(), Text> {
+    let domain = domain(metadata).or_else(|| url_domain(query));
+
+    // A guild allowlist can only narrow the global one, so a domain has to be on both when both are set.
+    for allowed in [&config.allowed_domains, &settings.allowed_domains] {
+        if allowed.is_empty() {
+            continue;
+        }
+
+        match &domain {
+            Some(domain) if allowed.iter().any(|allowed| matches_domain(domain, allowed)) => {},
+            Some(domain) => return Err(Text::DomainNotAllowed(domain.clone())),
+            None => return Err(Text::DomainNotAllowed(query.to_string())),
+        }
+    }
+
+    if let Some(domain) = domain
+        && config.blocked_domains.iter().chain(settings.blocked_domains.iter())
+            .any(|blocked| matches_domain(&domain, blocked)) {
+        return Err(Text::DomainNotAllowed(domain));
+    }
+
+    let titles = [&metadata.title, &metadata.track]
+        .into_iter()
+        .flatten()
+        .map(|title| title.to_lowercase())
+        .collect::<Vec<_>>();
+
+    if config.blocked_keywords.iter().chain(settings.blocked_keywords.iter())
+        .any(|keyword| titles.iter().any(|title| title.contains(&keyword.to_lowercase()))) {
+        return Err(Text::BlockedKeyword);
+    }
+
+    let uploaders = [&metadata.uploader, &metadata.artist]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if let Some(uploader) = uploaders.iter().find(|uploader| config.blocked_uploaders.iter()
+        .chain(settings.blocked_uploaders.iter())
+        .any(|blocked| blocked.eq_ignore_ascii_case(uploader))) {
+        return Err(Text::BlockedUploader(uploader.to_string()));
+    }
+
+    if let Some(max_duration) = config.max_duration.map(|minutes| u64::from(minutes) * 60)
+        && metadata.duration.is_some_and(|duration| u64::from(duration) > max_duration) {
+        return Err(Text::TrackTooLong(max_duration));
+    }
+
+    if (config.refuse_livestreams || settings.refuse_livestreams) && metadata.is_live == Some(true) {
+        return Err(Text::LivestreamNotAllowed);
+    }
+
+    if (config.refuse_age_restricted || settings.refuse_age_restricted) && metadata.age_limit.is_some_and(|age| age > 0) {
+        return Err(Text::AgeRestricted);
+    }
+
+    Ok(())
+}
+
+fn domain(metadata: &Metadata) -> Option<String> {
+    metadata.webpage_url_domain.as_ref()
+        .map(|domain| domain.to_lowercase())
+        .or_else(|| metadata.webpage_url.as_deref().and_then(url_domain))
+}
+
+fn url_domain(url: &str) -> Option<String> {
+    Url::parse(url).ok()
+        .and_then(|url| url.host_str().map(|host| host.to_lowercase()))
+}
+
+pub fn matches_domain(domain: &str, pattern: &str) -> bool {
+    let pattern = pattern.trim_start_matches("www.").to_lowercase();
+    let domain = domain.trim_start_matches("www.");
+
+    domain == pattern || domain.ends_with(&format!(".{pattern}"))
+}
@@ -1,9 +1,9 @@
 use serenity::{
     all::{
-        Command,
         CreateAutocompleteResponse,
         CreateInteractionResponse,
         AutocompleteChoice,
+        GuildId,
     },
     async_trait,
     model::{
@@ -12,15 +12,33 @@ use serenity::{
             CommandDataOptionValue,
         },
         gateway::Ready,
+        voice::VoiceState,
     },
     prelude::*,
 };
 
+use tracing::{
+    debug,
+    error,
+    field,
+    info,
+    info_span,
+    warn,
+    Instrument,
+};
+
 use crate::commands;
 
 use super::{
     response::normal_response,
     localization::Text,
+    idle,
+    stay,
+    queue_state,
+    shutdown,
+    health::Health,
+    cli::Config,
+    registration,
 };
 
 pub struct Handler;
@@ -28,25 +46,39 @@ pub struct Handler;
 #[async_trait]
 impl EventHandler for Handler {
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        if shutdown::is_shutting_down(&ctx.data).await {
+            if let Interaction::Command(command) = interaction {
+                normal_response(&ctx, &command, Text::Restarting.into()).await;
+            }
+            return;
+        }
+
         match interaction {
             Interaction::Command(command) => {
-                match command.data.name.as_str() {
-                    "play" => commands::play::run(&ctx, &command).await,
-                    "skip" => commands::skip::run(&ctx, &command).await,
-                    "disconnect" => commands::disconnect::run(&ctx, &command).await,
-                    "change_channel" => commands::change_channel::run(&ctx, &command).await,
-                    "queue" => commands::queue::run(&ctx, &command).await,
-                    "clear_queue" => commands::clear_queue::run(&ctx, &command).await,
-                    "shuffle" => commands::shuffle::run(&ctx, &command).await,
-                    "play_local" => commands::play_local::run(&ctx, &command).await,
-                    _ => normal_response(&ctx, &command, Text::UnknownCommand.into()).await,
+                let span = info_span!(
+                    "interaction",
+                    guild_id = ?command.guild_id,
+                    user_id = %command.user.id,
+                    command = %command.data.name,
+                    query = field::Empty,
+                );
+
+                // Other commands take settings and names rather than a search query.
+                if matches!(command.data.name.as_str(), "play" | "play_local")
+                    && let Some(query) = command.data.options.first().and_then(|option| option.value.as_str()) {
+                    span.record("query", query);
                 }
+
+                async {
+                    info!("Handling command");
+                    commands::run(&ctx, &command).await;
+                }.instrument(span).await;
             },
             Interaction::Autocomplete(command) => {
                 let value = match &command.data.options.first() {
                     Some(option) => &option.value,
                     None => {
-                        eprintln!("No options found in {command:?}");
+                        warn!("No options found in {command:?}");
                         return;
                     }
                 };
@@ -54,16 +86,16 @@ impl EventHandler for Handler {
                 let query = match value {
                     CommandDataOptionValue::Autocomplete {  value: query, .. } => query,
                     _ => {
-                        eprintln!("Expected a string query, got: {value:?}");
+                        warn!("Expected a string query, got: {value:?}");
                         return;
                     },
                 };
 
-                let autocomplete = match command.data.name.as_str() {
-                    "play_local" => commands::play_local::autocomplete(&ctx, query).await,
-                    _ => Vec::new(),
+                let autocomplete = match commands::find(&command.data.name) {
+                    Some(slash_command) => slash_command.autocomplete(&ctx, query).await,
+                    None => Vec::new(),
                 };
-                
+
                 let autocomplete_response = CreateAutocompleteResponse::new()
                     .set_choices(
                         autocomplete.into_iter()
@@ -74,27 +106,58 @@ impl EventHandler for Handler {
                 let response = CreateInteractionResponse::Autocomplete(autocomplete_response);
 
                 if let Err(e) = command.create_response(&ctx.http, response).await {
-                    eprintln!("Failed to create autocomplete response: {e:?}");
+                    error!(error = ?e, "Failed to create autocomplete response");
+                }
+            }
+            Interaction::Component(component) => {
+                let name = component.data.custom_id.split(':').next().unwrap_or_default();
+
+                match commands::find(name) {
+                    Some(slash_command) => slash_command.component(&ctx, &component).await,
+                    None => warn!(custom_id = %component.data.custom_id, "No handler for component"),
                 }
             }
             _ => {},
         }
     }
 
-    async fn ready(&self, ctx: Context, _: Ready) {
-        let commands = vec![
-            commands::play::register(),
-            commands::skip::register(),
-            commands::disconnect::register(),
-            commands::change_channel::register(),
-            commands::queue::register(),
-            commands::clear_queue::register(),
-            commands::shuffle::register(),
-            commands::play_local::register(),
-        ];
-
-        for cmd in commands {
-            let _ = Command::create_global_command(&ctx.http, cmd).await;
+    async fn voice_state_update(&self, ctx: Context, _: Option<VoiceState>, new: VoiceState) {
+        if let Some(guild_id) = new.guild_id {
+            idle::voice_state_changed(&ctx, guild_id).await;
         }
     }
-}
\ No newline at end of file
+
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        info!(user = %ready.user.name, guilds = ready.guilds.len(), "Connected to Discord");
+
+        let dev_guild = ctx.data.read().await
+            .get::<Config>()
+            .expect("Guaranteed to exist in the typemap.")
+            .dev_guild
+            .map(GuildId::from);
+
+        let registered = match registration::sync_commands(&ctx.http, dev_guild).await {
+            Ok(true) => {
+                info!(guild_id = ?dev_guild, "Registered commands");
+                true
+            }
+            Ok(false) => {
+                debug!(guild_id = ?dev_guild, "Commands are up to date");
+                true
+            }
+            Err(why) => {
+                error!(error = ?why, "Failed to register commands");
+                false
+            }
+        };
+
+        ctx.data.write().await
+            .get_mut::<Health>()
+            .expect("Guaranteed to exist in the typemap.")
+            .commands_registered = registered;
+
+        queue_state::restore_all(&ctx).await;
+
+        stay::rejoin_all(&ctx).await;
+    }
+}
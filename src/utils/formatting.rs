@@ -0,0 +1,53 @@
+use fluent_bundle::FluentArgs;
+
+use super::localization::locales;
+
+/// Number of tracks with the plural form of the locale, e.g. "5 utworów".
+pub fn tracks(count: usize, locale: &str) -> String {
+    plural("tracks", count as u64, locale)
+}
+
+/// Number of plays with the plural form of the locale.
+pub fn plays(count: usize, locale: &str) -> String {
+    plural("plays", count as u64, locale)
+}
+
+/// Spelled out duration, e.g. "5 hours 12 minutes". Seconds are only shown for durations under an hour.
+pub fn duration(duration: u64, locale: &str) -> String {
+    let hours = duration / 3600;
+    let minutes = (duration % 3600) / 60;
+    let seconds = duration % 60;
+
+    let mut parts = Vec::new();
+
+    if hours > 0 {
+        parts.push(plural("duration-hours", hours, locale));
+    }
+
+    if minutes > 0 {
+        parts.push(plural("duration-minutes", minutes, locale));
+    }
+
+    if hours == 0 && (seconds > 0 || minutes == 0) {
+        parts.push(plural("duration-seconds", seconds, locale));
+    }
+
+    parts.join(" ")
+}
+
+/// Time from now in its largest unit, rounded, e.g. "in 12 minutes".
+pub fn relative_duration(duration: u64, locale: &str) -> String {
+    match duration {
+        0..60 => plural("relative-seconds", duration, locale),
+        // Rounds to the nearest minute without reaching "60 minutes".
+        60..3570 => plural("relative-minutes", (duration + 30) / 60, locale),
+        _ => plural("relative-hours", (duration + 1800) / 3600, locale),
+    }
+}
+
+fn plural(id: &str, count: u64, locale: &str) -> String {
+    let mut args = FluentArgs::new();
+    args.set("count", count);
+
+    locales().format(locale, id, None, Some(&args))
+}
@@ -0,0 +1,125 @@
+use std::{
+    process::Stdio,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use serenity::{
+    gateway::{
+        ConnectionStage,
+        ShardManager,
+    },
+    prelude::*,
+};
+
+use serde_json::json;
+
+use tokio::{
+    process::Command,
+    time::timeout,
+};
+
+use super::{
+    audio::FileCache,
+    cli::Config,
+};
+
+const YTDLP_VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+// How long a yt-dlp check is reused by readiness probes instead of running yt-dlp again.
+const YTDLP_CHECK_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub struct Health {
+    pub commands_registered: bool,
+    ytdlp_checked: Option<(Instant, bool)>,
+}
+
+impl TypeMapKey for Health {
+    type Value = Health;
+}
+
+pub async fn liveness(shard_manager: &Arc<ShardManager>) -> (bool, String) {
+    let runners = shard_manager.runners.lock().await;
+
+    let connected = runners.values().any(|runner| runner.stage == ConnectionStage::Connected);
+
+    let latency = runners.values()
+        .filter_map(|runner| runner.latency)
+        .max()
+        .map(|latency| latency.as_millis() as u64);
+
+    let body = json!({
+        "alive": true,
+        "gateway_connected": connected,
+        "heartbeat_latency_ms": latency,
+    });
+
+    (connected, body.to_string())
+}
+
+pub async fn readiness(data: &RwLock<TypeMap>) -> (bool, String) {
+    let (commands_registered, library_loaded, ytdlp_checked, config) = {
+        let data = data.read().await;
+
+        let config = data.get::<Config>()
+            .cloned()
+            .expect("Guaranteed to exist in the typemap.");
+
+        let health = data.get::<Health>()
+            .expect("Guaranteed to exist in the typemap.");
+
+        let commands_registered = health.commands_registered;
+
+        let ytdlp_checked = health.ytdlp_checked
+            .filter(|(checked_at, _)| checked_at.elapsed() < YTDLP_CHECK_TTL)
+            .map(|(_, available)| available);
+
+        let library_loaded = config.audio_directory.is_none() || !data.get::<FileCache>()
+            .expect("Guaranteed to exist in the typemap.")
+            .is_empty();
+
+        (commands_registered, library_loaded, ytdlp_checked, config)
+    };
+
+    let ytdlp_available = match ytdlp_checked {
+        Some(available) => available,
+        None => {
+            let available = ytdlp_version(&config).await.is_some();
+
+            data.write().await
+                .get_mut::<Health>()
+                .expect("Guaranteed to exist in the typemap.")
+                .ytdlp_checked = Some((Instant::now(), available));
+
+            available
+        },
+    };
+
+    let ready = commands_registered && library_loaded && ytdlp_available;
+
+    let body = json!({
+        "ready": ready,
+        "commands_registered": commands_registered,
+        "ytdlp_available": ytdlp_available,
+        "local_library_loaded": library_loaded,
+    });
+
+    (ready, body.to_string())
+}
+
+pub async fn ytdlp_version(config: &Config) -> Option<String> {
+    let output = Command::new(config.ytdlp_path())
+        .arg("--version")
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output();
+
+    match timeout(YTDLP_VERSION_TIMEOUT, output).await {
+        Ok(Ok(output)) if output.status.success() => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+use serenity::{
+    model::id::GuildId,
+    prelude::*,
+};
+
+use tokio::task::AbortHandle;
+
+use tracing::error;
+
+use super::{
+    cli::{
+        Config,
+        IdleTimeout,
+    },
+    audio::{
+        get_bot_channel,
+        get_listeners,
+    },
+    stay,
+    queue_state,
+    settings::Settings,
+};
+
+#[derive(Default)]
+pub struct IdleState {
+    timers: HashMap<GuildId, AbortHandle>,
+    alone: HashSet<GuildId>,
+    paused: HashSet<GuildId>,
+}
+
+impl TypeMapKey for IdleState {
+    type Value = IdleState;
+}
+
+pub async fn schedule_leave(ctx: &Context, guild_id: GuildId, delay: Duration) {
+    let task_ctx = ctx.clone();
+
+    let task = tokio::spawn(async move {
+        tokio::time::sleep(delay).await;
+
+        task_ctx.data.write().await
+            .get_mut::<IdleState>()
+            .expect("Guaranteed to exist in the typemap.")
+            .timers
+            .remove(&guild_id);
+
+        leave(&task_ctx, guild_id).await;
+    });
+
+    let mut data = ctx.data.write().await;
+    let state = data.get_mut::<IdleState>().expect("Guaranteed to exist in the typemap.");
+
+    if let Some(previous) = state.timers.insert(guild_id, task.abort_handle()) {
+        previous.abort();
+    }
+}
+
+pub async fn cancel_leave(ctx: &Context, guild_id: GuildId) {
+    let mut data = ctx.data.write().await;
+    let state = data.get_mut::<IdleState>().expect("Guaranteed to exist in the typemap.");
+
+    if let Some(timer) = state.timers.remove(&guild_id) {
+        timer.abort();
+    }
+}
+
+pub async fn track_enqueued(ctx: &Context, guild_id: GuildId) {
+    let is_alone = {
+        let data = ctx.data.read().await;
+        data.get::<IdleState>()
+            .expect("Guaranteed to exist in the typemap.")
+            .alone
+            .contains(&guild_id)
+    };
+
+    if !is_alone {
+        cancel_leave(ctx, guild_id).await;
+    }
+}
+
+pub async fn leave(ctx: &Context, guild_id: GuildId) {
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    if manager.get(guild_id).is_some() && let Err(why) = manager.leave(guild_id).await {
+        error!(%guild_id, error = ?why, "Failed to leave voice channel");
+    }
+
+    queue_state::remove(&ctx.data, guild_id).await;
+
+    clear(ctx, guild_id).await;
+}
+
+pub async fn queue_ended(ctx: &Context, guild_id: GuildId) {
+    let idle_timeout = {
+        let data = ctx.data.read().await;
+
+        let config = data.get::<Config>()
+            .expect("Guaranteed to exist in the typemap.");
+
+        data.get::<Settings>()
+            .expect("Guaranteed to exist in the typemap.")
+            .get(guild_id)
+            .idle_timeout(config)
+    };
+
+    if stay::stay_channel(ctx, guild_id).await.is_some() {
+        return stay::play_fallback(ctx, guild_id).await;
+    }
+
+    match idle_timeout {
+        IdleTimeout::Immediate => leave(ctx, guild_id).await,
+        IdleTimeout::After(delay) => schedule_leave(ctx, guild_id, delay).await,
+        IdleTimeout::Never => {},
+    }
+}
+
+pub async fn voice_state_changed(ctx: &Context, guild_id: GuildId) {
+    let bot_channel = match get_bot_channel(ctx, guild_id) {
+        Some(channel_id) => channel_id,
+        None => return clear(ctx, guild_id).await,
+    };
+
+    let is_alone = get_listeners(ctx, guild_id, bot_channel).is_empty();
+
+    let was_alone = {
+        let data = ctx.data.read().await;
+        data.get::<IdleState>()
+            .expect("Guaranteed to exist in the typemap.")
+            .alone
+            .contains(&guild_id)
+    };
+
+    if is_alone == was_alone {
+        return;
+    }
+
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let handler_lock = match manager.get(guild_id) {
+        Some(handler) => handler,
+        None => return,
+    };
+
+    if is_alone {
+        let paused = {
+            let handler = handler_lock.lock().await;
+            match handler.queue().current() {
+                Some(_) => handler.queue().pause().is_ok(),
+                None => false,
+            }
+        };
+
+        {
+            let mut data = ctx.data.write().await;
+            let state = data.get_mut::<IdleState>().expect("Guaranteed to exist in the typemap.");
+            state.alone.insert(guild_id);
+            if paused {
+                state.paused.insert(guild_id);
+            }
+        }
+
+        let alone_timeout = {
+            let data = ctx.data.read().await;
+            data.get::<Config>()
+                .expect("Guaranteed to exist in the typemap.")
+                .alone_timeout
+        };
+
+        if stay::stay_channel(ctx, guild_id).await.is_none() {
+            schedule_leave(ctx, guild_id, Duration::from_secs(alone_timeout)).await;
+        }
+    } else {
+        cancel_leave(ctx, guild_id).await;
+
+        let was_paused = {
+            let mut data = ctx.data.write().await;
+            let state = data.get_mut::<IdleState>().expect("Guaranteed to exist in the typemap.");
+            state.alone.remove(&guild_id);
+            state.paused.remove(&guild_id)
+        };
+
+        let handler = handler_lock.lock().await;
+
+        if handler.queue().is_empty() {
+            drop(handler);
+            queue_ended(ctx, guild_id).await;
+        } else if was_paused && let Err(why) = handler.queue().resume() {
+            error!(%guild_id, error = ?why, "Failed to resume track");
+        }
+    }
+}
+
+async fn clear(ctx: &Context, guild_id: GuildId) {
+    let mut data = ctx.data.write().await;
+    let state = data.get_mut::<IdleState>().expect("Guaranteed to exist in the typemap.");
+
+    if let Some(timer) = state.timers.remove(&guild_id) {
+        timer.abort();
+    }
+    state.alone.remove(&guild_id);
+    state.paused.remove(&guild_id);
+}
@@ -1,6 +1,10 @@
 use std::{
     fs::read_dir,
-    path::PathBuf,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
     collections::HashMap,
 };
 
@@ -14,23 +18,28 @@ use lofty::{
     },
 };
 
+use tracing::error;
+
 use super::audio::Metadata;
 
 pub fn get_audio_files(path: &Option<PathBuf>) -> HashMap<String, Metadata> {
-    let read_dir = match path {
-        Some(dir) => read_dir(dir),
-        None => return HashMap::new(),
+    let Some(dir) = path else {
+        return HashMap::new();
     };
 
-    let paths = match read_dir {
-        Ok(paths) => paths,
+    match read_audio_files(dir) {
+        Ok(files) => files.into_iter().collect(),
         Err(why) => {
-            eprintln!("Failed to read audio directory: {why:?}");
-            return HashMap::new();
+            error!(error = ?why, "Failed to read audio directory");
+            HashMap::new()
         }
-    };
+    }
+}
+
+pub fn read_audio_files(dir: &Path) -> io::Result<Vec<(String, Metadata)>> {
+    let paths = read_dir(dir)?;
 
-    paths.filter_map(|entry| {
+    Ok(paths.filter_map(|entry| {
         let entry = entry.ok()?;
         let path = entry.path();
         if path.is_file() {
@@ -49,19 +58,22 @@ pub fn get_audio_files(path: &Option<PathBuf>) -> HashMap<String, Metadata> {
                 title: Some(filename),
                 uploader: None,
                 track: title,
-                artist: artist,
-                duration: duration,
+                artist,
+                duration,
                 thumbnail: None,
                 webpage_url: None,
                 url: path.to_str().map(|s| s.to_string()),
+                webpage_url_domain: None,
+                is_live: None,
+                age_limit: None,
                 }))
         } else {
             None
         }
-    }).collect()
+    }).collect())
 }
 
-fn get_tagged_file(path: &PathBuf) -> Option<TaggedFile> {
+fn get_tagged_file(path: &Path) -> Option<TaggedFile> {
     Probe::open(path)
         .ok()?
         .guess_file_type()
@@ -1,3 +1,52 @@
+use std::{
+    fs::{
+        read_dir,
+        read_to_string,
+    },
+    path::Path,
+    sync::OnceLock,
+};
+
+use fluent_bundle::{
+    concurrent::FluentBundle,
+    FluentArgs,
+    FluentResource,
+};
+
+use serenity::{
+    all::CommandOptionType,
+    builder::{
+        CreateCommand,
+        CreateCommandOption,
+    },
+};
+
+use tracing::warn;
+
+use unic_langid::LanguageIdentifier;
+
+use super::formatting;
+
+const DEFAULT_LOCALE: &str = "en-US";
+
+const EMBEDDED: &[(&str, &str)] = &[
+    ("en-US", include_str!("../../locales/en-US.ftl")),
+    ("pl", include_str!("../../locales/pl.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+    ("es-ES", include_str!("../../locales/es-ES.ftl")),
+    ("fr", include_str!("../../locales/fr.ftl")),
+    ("uk", include_str!("../../locales/uk.ftl")),
+];
+
+// Locales supported by Discord, used to localize command names and descriptions.
+const DISCORD_LOCALES: &[&str] = &[
+    "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl", "no",
+    "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+    "zh-CN", "ja", "zh-TW", "ko",
+];
+
+static LOCALES: OnceLock<Locales> = OnceLock::new();
+
 pub enum Text {
     UnknownCommand,
     UnknownTitle,
@@ -5,6 +54,8 @@ pub enum Text {
     Author,
     Duration,
     QueueLength,
+    QueuePosition,
+    Starts,
     NowPlaying,
     AddedToQueue,
     Queue,
@@ -24,65 +75,496 @@ pub enum Text {
     FailedToChangeChannel,
     ChangedChannel,
     NoSuchFile,
+    Statistics,
+    TopTracks,
+    TopRequesters,
+    TotalListeningTime,
+    NoStatistics,
+    StayEnabled,
+    StayDisabled,
+    QueueRestored,
+    Restarting,
+    SkipVoteRegistered(usize, usize),
+    MissingPermissions,
+    MissingServerPermissions,
+    SettingsUpdated,
+    LimitOutOfRange(u64),
+    ServerSettings,
+    SettingVolume,
+    SettingLanguage,
+    SettingDjRole,
+    SettingAnnouncementChannel,
+    SettingIdleTimeout,
+    SettingMaxQueueLength,
+    SettingVoteSkipThreshold,
+    SettingAnnounceNowPlaying,
+    SettingMaxUserTracks,
+    SettingMaxDuration,
+    SettingFairQueue,
+    SettingRefuseLivestreams,
+    SettingRefuseAgeRestricted,
+    SettingAllowedDomains,
+    SettingBlockedDomains,
+    SettingBlockedKeywords,
+    SettingBlockedUploaders,
+    PerUser,
+    NotSet,
+    CommandChannel,
+    Immediately,
+    Never,
+    Unlimited,
+    On,
+    Off,
+    InvalidIdleTimeout,
+    TrackTooLong(u64),
+    QueueFull(usize),
+    UserTrackLimit(usize),
+    RequestedBy,
+    DomainNotAllowed(String),
+    BlockedKeyword,
+    BlockedUploader(String),
+    LivestreamNotAllowed,
+    AgeRestricted,
+    YtDlpMissing,
+    FetchTimeout,
+    UnsupportedUrl,
+    VideoUnavailable,
+    GeoBlocked,
+    LoginRequired,
+    PrivateVideo,
+    AgeVerificationRequired,
+    RateLimited,
+    ExtractorFailed,
 }
 
 impl Text {
-    pub fn localization(&self, lang: &String) -> String {
-        match lang.as_str() {
-            "pl" => match self {
-                Text::UnknownCommand => "Nieznane polecenie".to_string(),
-                Text::UnknownTitle => "Nieznany tytuł".to_string(),
-                Text::Artist => "Artysta".to_string(),
-                Text::Author => "Autor".to_string(),
-                Text::Duration => "Czas trwania".to_string(),
-                Text::QueueLength => "Długość kolejki".to_string(),
-                Text::NowPlaying => "Teraz odtwarzane".to_string(),
-                Text::AddedToQueue => "Dodano do kolejki".to_string(),
-                Text::Queue => "Kolejka".to_string(),
-                Text::FailedToFetch => "Nie udało się pobrać informacji o utworze".to_string(),
-                Text::FailedToJoin => "Nie udało się dołączyć do kanału głosowego".to_string(),
-                Text::FailedToPlay => "Nie udało się odtworzyć utworu".to_string(),
-                Text::CommandOnlyInGuild => "To polecenie może być używane tylko na serwerze.".to_string(),
-                Text::UserMustBeInVoiceChannel => "Musisz być na kanale głosowym.".to_string(),
-                Text::BotMustBeInVoiceChannel => "Bot musi być na kanale głosowym.".to_string(),
-                Text::QueueEmpty => "Kolejka jest pusta.".to_string(),
-                Text::FailedToSkip => "Nie udało się pominąć utworu.".to_string(),
-                Text::Skipped => "Utwór pominięty.".to_string(),
-                Text::Shuffled => "Kolejka została przetasowana.".to_string(),
-                Text::Disconnected => "Rozłączono.".to_string(),
-                Text::FailedToDisconnect => "Nie udało się rozłączyć.".to_string(),
-                Text::ClearedQueue => "Kolejka została wyczyszczona.".to_string(),
-                Text::FailedToChangeChannel => "Nie udało się zmienić kanału głosowego.".to_string(),
-                Text::ChangedChannel => "Zmieniono kanał głosowy.".to_string(),
-                Text::NoSuchFile => "Nie znaleziono takiego pliku.".to_string(),
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            Text::UnknownCommand
+            | Text::FailedToFetch
+            | Text::FailedToJoin
+            | Text::FailedToPlay
+            | Text::FailedToSkip
+            | Text::FailedToDisconnect
+            | Text::FailedToChangeChannel
+            | Text::YtDlpMissing
+            | Text::FetchTimeout
+            | Text::RateLimited
+            | Text::ExtractorFailed => "error",
+            Text::CommandOnlyInGuild
+            | Text::UserMustBeInVoiceChannel
+            | Text::BotMustBeInVoiceChannel
+            | Text::QueueEmpty
+            | Text::NoSuchFile
+            | Text::Restarting
+            | Text::MissingPermissions
+            | Text::MissingServerPermissions
+            | Text::InvalidIdleTimeout
+            | Text::LimitOutOfRange(_)
+            | Text::TrackTooLong(_)
+            | Text::QueueFull(_)
+            | Text::UserTrackLimit(_)
+            | Text::DomainNotAllowed(_)
+            | Text::BlockedKeyword
+            | Text::BlockedUploader(_)
+            | Text::LivestreamNotAllowed
+            | Text::AgeRestricted
+            | Text::UnsupportedUrl
+            | Text::VideoUnavailable
+            | Text::GeoBlocked
+            | Text::LoginRequired
+            | Text::PrivateVideo
+            | Text::AgeVerificationRequired => "rejected",
+            Text::UnknownTitle
+            | Text::Artist
+            | Text::Author
+            | Text::Duration
+            | Text::QueueLength
+            | Text::QueuePosition
+            | Text::Starts
+            | Text::NowPlaying
+            | Text::AddedToQueue
+            | Text::Queue
+            | Text::Skipped
+            | Text::Shuffled
+            | Text::Disconnected
+            | Text::ClearedQueue
+            | Text::ChangedChannel
+            | Text::Statistics
+            | Text::TopTracks
+            | Text::TopRequesters
+            | Text::TotalListeningTime
+            | Text::NoStatistics
+            | Text::StayEnabled
+            | Text::StayDisabled
+            | Text::QueueRestored
+            | Text::SkipVoteRegistered(_, _)
+            | Text::SettingsUpdated
+            | Text::ServerSettings
+            | Text::SettingVolume
+            | Text::SettingLanguage
+            | Text::SettingDjRole
+            | Text::SettingAnnouncementChannel
+            | Text::SettingIdleTimeout
+            | Text::SettingMaxQueueLength
+            | Text::SettingVoteSkipThreshold
+            | Text::SettingAnnounceNowPlaying
+            | Text::SettingMaxUserTracks
+            | Text::SettingMaxDuration
+            | Text::SettingFairQueue
+            | Text::SettingRefuseLivestreams
+            | Text::SettingRefuseAgeRestricted
+            | Text::SettingAllowedDomains
+            | Text::SettingBlockedDomains
+            | Text::SettingBlockedKeywords
+            | Text::SettingBlockedUploaders
+            | Text::PerUser
+            | Text::NotSet
+            | Text::CommandChannel
+            | Text::Immediately
+            | Text::Never
+            | Text::Unlimited
+            | Text::On
+            | Text::Off
+            | Text::RequestedBy => "ok",
+        }
+    }
+
+    fn message(&self, locale: &str) -> (&'static str, FluentArgs<'_>) {
+        let mut args = FluentArgs::new();
+
+        let id = match self {
+            Text::UnknownCommand => "unknown-command",
+            Text::UnknownTitle => "unknown-title",
+            Text::Artist => "artist",
+            Text::Author => "author",
+            Text::Duration => "duration",
+            Text::QueueLength => "queue-length",
+            Text::QueuePosition => "queue-position",
+            Text::Starts => "starts",
+            Text::NowPlaying => "now-playing",
+            Text::AddedToQueue => "added-to-queue",
+            Text::Queue => "queue",
+            Text::FailedToFetch => "failed-to-fetch",
+            Text::FailedToJoin => "failed-to-join",
+            Text::FailedToPlay => "failed-to-play",
+            Text::CommandOnlyInGuild => "command-only-in-guild",
+            Text::UserMustBeInVoiceChannel => "user-must-be-in-voice-channel",
+            Text::BotMustBeInVoiceChannel => "bot-must-be-in-voice-channel",
+            Text::QueueEmpty => "queue-empty",
+            Text::FailedToSkip => "failed-to-skip",
+            Text::Skipped => "skipped",
+            Text::Shuffled => "shuffled",
+            Text::Disconnected => "disconnected",
+            Text::FailedToDisconnect => "failed-to-disconnect",
+            Text::ClearedQueue => "cleared-queue",
+            Text::FailedToChangeChannel => "failed-to-change-channel",
+            Text::ChangedChannel => "changed-channel",
+            Text::NoSuchFile => "no-such-file",
+            Text::Statistics => "statistics",
+            Text::TopTracks => "top-tracks",
+            Text::TopRequesters => "top-requesters",
+            Text::TotalListeningTime => "total-listening-time",
+            Text::NoStatistics => "no-statistics",
+            Text::StayEnabled => "stay-enabled",
+            Text::StayDisabled => "stay-disabled",
+            Text::QueueRestored => "queue-restored",
+            Text::Restarting => "restarting",
+            Text::SkipVoteRegistered(votes, required) => {
+                args.set("votes", *votes);
+                args.set("required", *required);
+                "skip-vote-registered"
+            },
+            Text::MissingPermissions => "missing-permissions",
+            Text::MissingServerPermissions => "missing-server-permissions",
+            Text::SettingsUpdated => "settings-updated",
+            Text::LimitOutOfRange(max) => {
+                args.set("max", *max);
+                "limit-out-of-range"
+            },
+            Text::ServerSettings => "server-settings",
+            Text::SettingVolume => "setting-volume",
+            Text::SettingLanguage => "setting-language",
+            Text::SettingDjRole => "setting-dj-role",
+            Text::SettingAnnouncementChannel => "setting-announcement-channel",
+            Text::SettingIdleTimeout => "setting-idle-timeout",
+            Text::SettingMaxQueueLength => "setting-max-queue-length",
+            Text::SettingVoteSkipThreshold => "setting-vote-skip-threshold",
+            Text::SettingAnnounceNowPlaying => "setting-announce-now-playing",
+            Text::SettingMaxUserTracks => "setting-max-user-tracks",
+            Text::SettingMaxDuration => "setting-max-duration",
+            Text::SettingFairQueue => "setting-fair-queue",
+            Text::SettingRefuseLivestreams => "setting-refuse-livestreams",
+            Text::SettingRefuseAgeRestricted => "setting-refuse-age-restricted",
+            Text::SettingAllowedDomains => "setting-allowed-domains",
+            Text::SettingBlockedDomains => "setting-blocked-domains",
+            Text::SettingBlockedKeywords => "setting-blocked-keywords",
+            Text::SettingBlockedUploaders => "setting-blocked-uploaders",
+            Text::PerUser => "per-user",
+            Text::NotSet => "not-set",
+            Text::CommandChannel => "command-channel",
+            Text::Immediately => "immediately",
+            Text::Never => "never",
+            Text::Unlimited => "unlimited",
+            Text::On => "on",
+            Text::Off => "off",
+            Text::InvalidIdleTimeout => "invalid-idle-timeout",
+            Text::TrackTooLong(max) => {
+                args.set("max", formatting::duration(*max, locale));
+                "track-too-long"
+            },
+            Text::QueueFull(max) => {
+                args.set("max", *max);
+                "queue-full"
+            },
+            Text::UserTrackLimit(max) => {
+                args.set("max", *max);
+                "user-track-limit"
             },
-            _ => match self {
-                Text::UnknownCommand => "Unknown command".to_string(),
-                Text::UnknownTitle => "Unknown title".to_string(),
-                Text::Artist => "Artist".to_string(),
-                Text::Author => "Author".to_string(),
-                Text::Duration => "Duration".to_string(),
-                Text::QueueLength => "Queue length".to_string(),
-                Text::NowPlaying => "Now playing".to_string(),
-                Text::AddedToQueue => "Added to queue".to_string(),
-                Text::Queue => "Queue".to_string(),
-                Text::FailedToFetch => "Failed to fetch track info".to_string(),
-                Text::FailedToJoin => "Failed to join voice channel".to_string(),
-                Text::FailedToPlay => "Failed to play track".to_string(),
-                Text::CommandOnlyInGuild => "This command can only be used in a server.".to_string(),
-                Text::UserMustBeInVoiceChannel => "You must be in a voice channel.".to_string(),
-                Text::BotMustBeInVoiceChannel => "Bot must be in a voice channel.".to_string(),
-                Text::QueueEmpty => "Queue is empty.".to_string(),
-                Text::FailedToSkip => "Failed to skip track.".to_string(),
-                Text::Skipped => "Track skipped.".to_string(),
-                Text::Shuffled => "Queue shuffled.".to_string(),
-                Text::Disconnected => "Disconnected.".to_string(),
-                Text::FailedToDisconnect => "Failed to disconnect.".to_string(),
-                Text::ClearedQueue => "Cleared queue.".to_string(),
-                Text::FailedToChangeChannel => "Failed to change voice channel.".to_string(),
-                Text::ChangedChannel => "Changed voice channel.".to_string(),
-                Text::NoSuchFile => "No such file found.".to_string(),
+            Text::RequestedBy => "requested-by",
+            Text::DomainNotAllowed(domain) => {
+                args.set("domain", domain.as_str());
+                "domain-not-allowed"
             },
+            Text::BlockedKeyword => "blocked-keyword",
+            Text::BlockedUploader(uploader) => {
+                args.set("uploader", uploader.as_str());
+                "blocked-uploader"
+            },
+            Text::LivestreamNotAllowed => "livestream-not-allowed",
+            Text::AgeRestricted => "age-restricted",
+            Text::YtDlpMissing => "yt-dlp-missing",
+            Text::FetchTimeout => "fetch-timeout",
+            Text::UnsupportedUrl => "unsupported-url",
+            Text::VideoUnavailable => "video-unavailable",
+            Text::GeoBlocked => "geo-blocked",
+            Text::LoginRequired => "login-required",
+            Text::PrivateVideo => "private-video",
+            Text::AgeVerificationRequired => "age-verification-required",
+            Text::RateLimited => "rate-limited",
+            Text::ExtractorFailed => "extractor-failed",
+        };
+
+        (id, args)
+    }
+
+    pub fn localization(&self, lang: &str) -> String {
+        let (id, args) = self.message(lang);
+
+        locales().format(lang, id, None, Some(&args))
+    }
+}
+
+pub struct Locales {
+    // The default locale comes first.
+    bundles: Vec<(String, FluentBundle<FluentResource>)>,
+}
+
+impl Locales {
+    /// Loads embedded locales, then `<locale>.ftl` files from `directory`, which override
+    /// embedded messages or add new locales.
+    pub fn load(directory: Option<&Path>) -> Locales {
+        let mut locales = Locales { bundles: Vec::new() };
+
+        for (locale, source) in EMBEDDED {
+            let resource = FluentResource::try_new(source.to_string())
+                .expect("Embedded locales are valid.");
+
+            locales.add(locale, resource);
+        }
+
+        let Some(directory) = directory else {
+            return locales;
+        };
+
+        let entries = match read_dir(directory) {
+            Ok(entries) => entries,
+            Err(why) => {
+                warn!(error = ?why, directory = %directory.display(), "Failed to read locales directory");
+                return locales;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.extension().is_none_or(|extension| extension != "ftl") {
+                continue;
+            }
+
+            let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let source = match read_to_string(&path) {
+                Ok(source) => source,
+                Err(why) => {
+                    warn!(error = ?why, path = %path.display(), "Failed to read locale file");
+                    continue;
+                }
+            };
+
+            let resource = match FluentResource::try_new(source) {
+                Ok(resource) => resource,
+                Err((resource, errors)) => {
+                    warn!(?errors, path = %path.display(), "Locale file contains errors, skipping invalid entries");
+                    resource
+                }
+            };
+
+            locales.add(locale, resource);
         }
+
+        locales
+    }
+
+    fn add(&mut self, locale: &str, resource: FluentResource) {
+        if let Some((_, bundle)) = self.bundles.iter_mut().find(|(name, _)| name.eq_ignore_ascii_case(locale)) {
+            bundle.add_resource_overriding(resource);
+            return;
+        }
+
+        let language = match locale.parse::<LanguageIdentifier>() {
+            Ok(language) => language,
+            Err(why) => {
+                warn!(error = ?why, locale, "Invalid locale name");
+                return;
+            }
+        };
+
+        let mut bundle = FluentBundle::new_concurrent(vec![language]);
+        bundle.set_use_isolating(false);
+        bundle.add_resource_overriding(resource);
+
+        self.bundles.push((locale.to_string(), bundle));
+    }
+
+    /// Returns the bundle of the locale, another variant of its language (`en-GB` → `en-US`)
+    /// or the default locale.
+    fn resolve(&self, locale: &str) -> &(String, FluentBundle<FluentResource>) {
+        let language = locale.split('-').next().unwrap_or(locale);
+
+        self.bundles.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(locale))
+            .or_else(|| self.bundles.iter().find(|(name, _)| {
+                name.split('-').next().is_some_and(|name| name.eq_ignore_ascii_case(language))
+            }))
+            .unwrap_or(&self.bundles[0])
+    }
+
+    /// Formats the message or its attribute, falling back to the default locale when it's missing.
+    pub fn format(&self, locale: &str, id: &str, attribute: Option<&str>, args: Option<&FluentArgs>) -> String {
+        let (_, bundle) = self.resolve(locale);
+
+        [bundle, &self.bundles[0].1].into_iter()
+            .find_map(|bundle| {
+                let message = bundle.get_message(id)?;
+
+                let pattern = match attribute {
+                    Some(attribute) => message.get_attribute(attribute)?.value(),
+                    None => message.value()?,
+                };
+
+                let mut errors = Vec::new();
+                let text = bundle.format_pattern(pattern, args, &mut errors);
+
+                if !errors.is_empty() {
+                    warn!(?errors, id, locale, "Failed to format message");
+                }
+
+                Some(text.into_owned())
+            })
+            .unwrap_or_else(|| id.to_string())
     }
-}
\ No newline at end of file
+
+    /// Returns loaded locales paired with their native names.
+    pub fn languages(&self) -> Vec<(String, String)> {
+        self.bundles.iter()
+            .map(|(locale, _)| (locale.clone(), self.format(locale, "language-name", None, None)))
+            .collect()
+    }
+
+    /// Returns Discord locales which resolve to a non-default locale, paired with the formatted message.
+    fn localizations(&self, id: &str, attribute: Option<&str>) -> Vec<(&'static str, String)> {
+        DISCORD_LOCALES.iter()
+            .filter(|locale| !self.resolve(locale).0.eq_ignore_ascii_case(DEFAULT_LOCALE))
+            .map(|locale| (*locale, self.format(locale, id, attribute, None)))
+            .collect()
+    }
+}
+
+pub fn init(directory: Option<&Path>) {
+    if LOCALES.set(Locales::load(directory)).is_err() {
+        warn!("Locales are already loaded");
+    }
+}
+
+/// Whether the locale is embedded or has a `<locale>.ftl` file in `directory`, checked without loading it.
+pub fn is_available(directory: Option<&Path>, locale: &str) -> bool {
+    EMBEDDED.iter().any(|(name, _)| name.eq_ignore_ascii_case(locale))
+        || directory.and_then(|directory| read_dir(directory).ok()).is_some_and(|entries| {
+            entries.flatten().any(|entry| {
+                let path = entry.path();
+                path.extension().is_some_and(|extension| extension == "ftl")
+                    && path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| stem.eq_ignore_ascii_case(locale))
+            })
+        })
+}
+
+pub fn locales() -> &'static Locales {
+    LOCALES.get_or_init(|| Locales::load(None))
+}
+
+/// Creates a command with name and description localized from `command-<name>`.
+pub fn command(name: &str) -> CreateCommand {
+    let id = format!("command-{name}");
+    let locales = locales();
+
+    let mut command = CreateCommand::new(name)
+        .description(locales.format(DEFAULT_LOCALE, &id, Some("description"), None));
+
+    for (locale, localized) in locales.localizations(&id, None) {
+        command = command.name_localized(locale, localized);
+    }
+
+    for (locale, localized) in locales.localizations(&id, Some("description")) {
+        command = command.description_localized(locale, localized);
+    }
+
+    command
+}
+
+/// Creates an option localized from `command-<parent>-<name>`, e.g. `command-settings-dj_role-role`.
+pub fn option(kind: CommandOptionType, parent: &str, name: &str) -> CreateCommandOption {
+    let id = format!("command-{parent}-{name}");
+    let locales = locales();
+
+    let mut option = CreateCommandOption::new(kind, name, locales.format(DEFAULT_LOCALE, &id, Some("description"), None));
+
+    for (locale, localized) in locales.localizations(&id, None) {
+        option = option.name_localized(locale, localized);
+    }
+
+    for (locale, localized) in locales.localizations(&id, Some("description")) {
+        option = option.description_localized(locale, localized);
+    }
+
+    option
+}
+
+/// Adds string choices localized from `command-<parent>-<value>`.
+pub fn string_choices(mut option: CreateCommandOption, parent: &str, values: &[&str]) -> CreateCommandOption {
+    let locales = locales();
+
+    for value in values {
+        let id = format!("command-{parent}-{value}");
+
+        option = option.add_string_choice_localized(
+            locales.format(DEFAULT_LOCALE, &id, None, None),
+            *value,
+            locales.localizations(&id, None),
+        );
+    }
+
+    option
+}
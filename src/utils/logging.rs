@@ -0,0 +1,17 @@
+use tracing_subscriber::EnvFilter;
+
+use super::cli::{
+    Config,
+    LogFormat,
+};
+
+pub fn init(config: &Config) {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(&config.log_filter))
+        .with_writer(std::io::stderr);
+
+    match config.log_format {
+        LogFormat::Text => builder.init(),
+        LogFormat::Json => builder.json().with_current_span(true).with_span_list(false).init(),
+    }
+}
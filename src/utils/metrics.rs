@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use prometheus::{
+    Encoder,
+    Histogram,
+    HistogramOpts,
+    IntCounter,
+    IntCounterVec,
+    IntGauge,
+    IntGaugeVec,
+    Opts,
+    Registry,
+    TextEncoder,
+};
+
+use serenity::prelude::*;
+
+use songbird::Songbird;
+
+use tracing::error;
+
+use super::audio::FileCache;
+
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    voice_connections: IntGauge,
+    queue_length: IntGaugeVec,
+    local_library_size: IntGauge,
+    commands: IntCounterVec,
+    ytdlp_duration: Histogram,
+    ytdlp_failures: IntCounterVec,
+    metadata_cache_hits: IntCounter,
+    metadata_cache_misses: IntCounter,
+    track_start_failures: IntCounter,
+}
+
+impl TypeMapKey for Metrics {
+    type Value = Metrics;
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new().expect("Metric definitions are valid.")
+    }
+}
+
+impl Metrics {
+    fn new() -> prometheus::Result<Metrics> {
+        let registry = Registry::new_custom(Some("musicbot".to_string()), None)?;
+
+        let metrics = Metrics {
+            voice_connections: IntGauge::new("voice_connections", "Number of connected voice channels")?,
+            queue_length: IntGaugeVec::new(Opts::new("queue_length", "Number of tracks in the queue"), &["guild_id"])?,
+            local_library_size: IntGauge::new("local_library_size", "Number of local audio files")?,
+            commands: IntCounterVec::new(Opts::new("commands_total", "Handled commands"), &["command", "outcome"])?,
+            ytdlp_duration: Histogram::with_opts(
+                HistogramOpts::new("ytdlp_duration_seconds", "Duration of yt-dlp invocations")
+                    .buckets(vec![0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0])
+            )?,
+            ytdlp_failures: IntCounterVec::new(Opts::new("ytdlp_failures_total", "Failed yt-dlp invocations"), &["category"])?,
+            metadata_cache_hits: IntCounter::new("metadata_cache_hits_total", "Metadata cache hits")?,
+            metadata_cache_misses: IntCounter::new("metadata_cache_misses_total", "Metadata cache misses")?,
+            track_start_failures: IntCounter::new("track_start_failures_total", "Tracks that failed to start playing")?,
+            registry,
+        };
+
+        metrics.registry.register(Box::new(metrics.voice_connections.clone()))?;
+        metrics.registry.register(Box::new(metrics.queue_length.clone()))?;
+        metrics.registry.register(Box::new(metrics.local_library_size.clone()))?;
+        metrics.registry.register(Box::new(metrics.commands.clone()))?;
+        metrics.registry.register(Box::new(metrics.ytdlp_duration.clone()))?;
+        metrics.registry.register(Box::new(metrics.ytdlp_failures.clone()))?;
+        metrics.registry.register(Box::new(metrics.metadata_cache_hits.clone()))?;
+        metrics.registry.register(Box::new(metrics.metadata_cache_misses.clone()))?;
+        metrics.registry.register(Box::new(metrics.track_start_failures.clone()))?;
+
+        Ok(metrics)
+    }
+
+    pub fn command(&self, command: &str, outcome: &str) {
+        self.commands.with_label_values(&[command, outcome]).inc();
+    }
+
+    pub fn ytdlp_finished(&self, duration: Duration) {
+        self.ytdlp_duration.observe(duration.as_secs_f64());
+    }
+
+    pub fn ytdlp_failed(&self, category: &str) {
+        self.ytdlp_failures.with_label_values(&[category]).inc();
+    }
+
+    pub fn metadata_cache(&self, hit: bool) {
+        if hit {
+            self.metadata_cache_hits.inc();
+        } else {
+            self.metadata_cache_misses.inc();
+        }
+    }
+
+    pub fn track_start_failed(&self) {
+        self.track_start_failures.inc();
+    }
+
+    pub async fn render(&self, data: &RwLock<TypeMap>, manager: &Songbird) -> String {
+        let library_size = data.read().await
+            .get::<FileCache>()
+            .expect("Guaranteed to exist in the typemap.")
+            .len();
+
+        self.local_library_size.set(library_size as i64);
+
+        self.queue_length.reset();
+
+        let mut connections = 0;
+
+        for (guild_id, handler_lock) in manager.iter().collect::<Vec<_>>() {
+            let handler = handler_lock.lock().await;
+
+            if handler.current_channel().is_some() {
+                connections += 1;
+            }
+
+            self.queue_length
+                .with_label_values(&[&guild_id.0.to_string()])
+                .set(handler.queue().len() as i64);
+        }
+
+        self.voice_connections.set(connections);
+
+        let mut buffer = Vec::new();
+
+        if let Err(why) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!(error = ?why, "Failed to encode metrics");
+        }
+
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+pub async fn metrics(data: &RwLock<TypeMap>) -> Metrics {
+    data.read().await
+        .get::<Metrics>()
+        .cloned()
+        .expect("Guaranteed to exist in the typemap.")
+}
@@ -1,6 +1,25 @@
 pub mod audio;
 pub mod response;
 pub mod localization;
+pub mod formatting;
 pub mod local_files;
 pub mod event_handler;
-pub mod cli;
\ No newline at end of file
+pub mod cli;
+pub mod play_log;
+pub mod idle;
+pub mod stay;
+pub mod queue_state;
+pub mod shutdown;
+pub mod skip_votes;
+pub mod storage;
+pub mod settings;
+pub mod permissions;
+pub mod content_policy;
+pub mod track_error;
+pub mod logging;
+pub mod metrics;
+pub mod status_server;
+pub mod health;
+pub mod token;
+pub mod admin;
+pub mod registration;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use serenity::{
+    model::application::CommandInteraction,
+    prelude::*,
+};
+
+use songbird::tracks::TrackHandle;
+
+use super::{
+    audio::TrackData,
+    settings::guild_settings,
+};
+
+pub fn is_manager(command: &CommandInteraction) -> bool {
+    command.member.as_ref()
+        .and_then(|member| member.permissions)
+        .is_some_and(|permissions| permissions.manage_guild())
+}
+
+pub async fn is_dj(ctx: &Context, command: &CommandInteraction) -> bool {
+    if is_manager(command) {
+        return true;
+    }
+
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => return false,
+    };
+
+    match guild_settings(ctx, guild_id).await.dj_role {
+        Some(role) => command.member.as_ref().is_some_and(|member| member.roles.contains(&role)),
+        None => false,
+    }
+}
+
+pub async fn is_allowed(ctx: &Context, command: &CommandInteraction, tracks: &[TrackHandle]) -> bool {
+    let guild_id = match command.guild_id {
+        Some(id) => id,
+        None => return false,
+    };
+
+    if guild_settings(ctx, guild_id).await.dj_role.is_none() || is_dj(ctx, command).await {
+        return true;
+    }
+
+    !tracks.is_empty() && tracks.iter().all(|track| track.data::<TrackData>().requester.id == command.user.id)
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    fs::{
+        read_to_string,
+        OpenOptions,
+    },
+    io::Write,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use serenity::{
+    model::id::{
+        GuildId,
+        UserId,
+    },
+    prelude::TypeMapKey,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use songbird::tracks::TrackHandle;
+
+use uuid::Uuid;
+
+use tracing::{
+    error,
+    warn,
+};
+
+use super::{
+    audio::SourceKind,
+    storage::data_file,
+};
+
+const PLAY_LOG_FILE: &str = "play_log.jsonl";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PlayRecord {
+    pub title: Option<String>,
+    pub source: Option<String>,
+    pub requester: UserId,
+    #[serde(default)]
+    pub requester_name: Option<String>,
+    #[serde(default)]
+    pub kind: SourceKind,
+    pub guild_id: GuildId,
+    pub started_at: u64,
+    pub listened: u64,
+    pub skipped: bool,
+}
+
+pub struct Statistics {
+    pub top_tracks: Vec<(String, usize)>,
+    pub top_requesters: Vec<(UserId, usize)>,
+    pub total_listened: u64,
+}
+
+/// How long individual records are kept in memory. Older plays only count towards the all time totals.
+pub const RECENT_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+#[derive(Default)]
+struct Tally {
+    tracks: HashMap<String, (String, usize)>,
+    requesters: HashMap<UserId, usize>,
+    total_listened: u64,
+}
+
+impl Tally {
+    fn add(&mut self, record: &PlayRecord) {
+        let title = record.title.clone().unwrap_or_default();
+        let key = record.source.clone().unwrap_or_else(|| title.clone());
+        self.tracks.entry(key).or_insert((title, 0)).1 += 1;
+        *self.requesters.entry(record.requester).or_insert(0) += 1;
+        self.total_listened += record.listened;
+    }
+
+    fn statistics(&self, limit: usize) -> Statistics {
+        let mut top_tracks = self.tracks.values()
+            .map(|(title, count)| (title.clone(), *count))
+            .collect::<Vec<_>>();
+        top_tracks.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_tracks.truncate(limit);
+
+        let mut top_requesters = self.requesters.iter()
+            .map(|(requester, count)| (*requester, *count))
+            .collect::<Vec<_>>();
+        top_requesters.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_requesters.truncate(limit);
+
+        Statistics {
+            top_tracks,
+            top_requesters,
+            total_listened: self.total_listened,
+        }
+    }
+}
+
+pub struct PlayLog {
+    totals: HashMap<GuildId, Tally>,
+    recent: VecDeque<PlayRecord>,
+    started: HashMap<Uuid, u64>,
+    path: Option<PathBuf>,
+}
+
+impl TypeMapKey for PlayLog {
+    type Value = PlayLog;
+}
+
+impl PlayLog {
+    pub fn load(directory: &Option<PathBuf>) -> PlayLog {
+        let path = data_file(directory, PLAY_LOG_FILE);
+
+        let mut play_log = PlayLog {
+            totals: HashMap::new(),
+            recent: VecDeque::new(),
+            started: HashMap::new(),
+            path: path.clone(),
+        };
+
+        match path.as_ref().map(read_to_string) {
+            Some(Ok(content)) => {
+                let records = content.lines()
+                    .filter_map(|line| match serde_json::from_str(line) {
+                        Ok(record) => Some(record),
+                        Err(why) => {
+                            warn!(error = ?why, "Skipping malformed play log entry");
+                            None
+                        }
+                    });
+
+                for record in records {
+                    play_log.record(record);
+                }
+            },
+            Some(Err(why)) if why.kind() != std::io::ErrorKind::NotFound => {
+                error!(error = ?why, "Failed to read play log");
+            },
+            _ => {},
+        }
+
+        play_log
+    }
+
+    pub fn path(&self) -> Option<PathBuf> {
+        self.path.clone()
+    }
+
+    pub fn start(&mut self, handle: &TrackHandle) {
+        self.started.entry(handle.uuid()).or_insert_with(now);
+    }
+
+    /// When the track started playing, none if it never did.
+    pub fn take_start(&mut self, handle: &TrackHandle) -> Option<u64> {
+        self.started.remove(&handle.uuid())
+    }
+
+    /// Adds the play to the in memory statistics. Writing it to disk is left to [`append`].
+    pub fn record(&mut self, record: PlayRecord) {
+        self.totals.entry(record.guild_id).or_default().add(&record);
+
+        let cutoff = now().saturating_sub(RECENT_WINDOW);
+        while self.recent.front().is_some_and(|oldest| oldest.started_at < cutoff) {
+            self.recent.pop_front();
+        }
+
+        if record.started_at >= cutoff {
+            self.recent.push_back(record);
+        }
+    }
+
+    /// Statistics of the guild since the given time, or of all time when it's 0.
+    /// Other periods only cover plays from the last [`RECENT_WINDOW`].
+    pub fn statistics(&self, guild_id: GuildId, since: u64, limit: usize) -> Statistics {
+        if since == 0 {
+            return self.totals.get(&guild_id)
+                .map(|tally| tally.statistics(limit))
+                .unwrap_or_else(|| Tally::default().statistics(limit));
+        }
+
+        let mut tally = Tally::default();
+
+        self.recent.iter()
+            .filter(|record| record.guild_id == guild_id && record.started_at >= since)
+            .for_each(|record| tally.add(record));
+
+        tally.statistics(limit)
+    }
+}
+
+/// Appends the records to the play log file. Blocking, so run it outside of any locks.
+pub fn append(path: &Path, records: &[PlayRecord]) {
+    let mut lines = String::new();
+
+    for record in records {
+        match serde_json::to_string(record) {
+            Ok(line) => {
+                lines.push_str(&line);
+                lines.push('\n');
+            },
+            Err(why) => error!(error = ?why, "Failed to serialize play log entry"),
+        }
+    }
+
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()));
+
+    if let Err(why) = result {
+        error!(error = ?why, "Failed to write play log");
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
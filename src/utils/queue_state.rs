@@ -0,0 +1,390 @@
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use serenity::{
+    model::id::{
+        ChannelId,
+        GuildId,
+        UserId,
+    },
+    prelude::*,
+};
+
+use songbird::SongbirdKey;
+
+use tokio::sync::Mutex as AsyncMutex;
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use tracing::{
+    error,
+    warn,
+};
+
+use super::{
+    audio::{
+        join,
+        local_track,
+        resolve_query,
+        FileCache,
+        Requester,
+        SourceKind,
+        TrackData,
+    },
+    localization::Text,
+    track_error::TrackError,
+    response::channel_message,
+    settings::{
+        guild_locale,
+        guild_volume,
+    },
+    storage::{
+        data_file,
+        load_json,
+        save_json,
+    },
+};
+
+const QUEUES_FILE: &str = "queues.json";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct QueueEntry {
+    pub source: String,
+    pub local: bool,
+    pub requester: UserId,
+    #[serde(default)]
+    pub requester_name: String,
+    #[serde(default)]
+    pub requester_avatar: Option<String>,
+    #[serde(default)]
+    pub requested_at: u64,
+    #[serde(default)]
+    pub kind: SourceKind,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct QueueSnapshot {
+    pub voice_channel: ChannelId,
+    pub text_channel: Option<ChannelId>,
+    pub position: u64,
+    pub entries: Vec<QueueEntry>,
+}
+
+pub struct QueueSnapshots {
+    snapshots: HashMap<GuildId, QueueSnapshot>,
+    text_channels: HashMap<GuildId, ChannelId>,
+    path: Option<PathBuf>,
+    frozen: bool,
+    restored: bool,
+    // Guilds whose queues are being restored, their snapshots are kept until it finishes.
+    restoring: HashSet<GuildId>,
+    // Held while writing the file so an older copy never overwrites a newer one.
+    writer: Arc<AsyncMutex<()>>,
+}
+
+impl TypeMapKey for QueueSnapshots {
+    type Value = QueueSnapshots;
+}
+
+impl QueueSnapshots {
+    pub fn load(directory: &Option<PathBuf>) -> QueueSnapshots {
+        let path = data_file(directory, QUEUES_FILE);
+        let snapshots: HashMap<GuildId, QueueSnapshot> = load_json(&path);
+
+        let text_channels = snapshots.iter()
+            .filter_map(|(guild_id, snapshot)| snapshot.text_channel.map(|channel_id| (*guild_id, channel_id)))
+            .collect();
+
+        QueueSnapshots {
+            snapshots,
+            text_channels,
+            path,
+            frozen: false,
+            restored: false,
+            restoring: HashSet::new(),
+            writer: Arc::new(AsyncMutex::new(())),
+        }
+    }
+
+    pub fn set_text_channel(&mut self, guild_id: GuildId, channel_id: ChannelId) {
+        self.text_channels.insert(guild_id, channel_id);
+    }
+
+    pub fn text_channel(&self, guild_id: GuildId) -> Option<ChannelId> {
+        self.text_channels.get(&guild_id).copied()
+    }
+
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    fn update(&mut self, guild_id: GuildId, snapshot: Option<QueueSnapshot>) -> bool {
+        if self.frozen || self.restoring.contains(&guild_id) {
+            return false;
+        }
+
+        match snapshot {
+            Some(snapshot) => self.snapshots.insert(guild_id, snapshot),
+            None => self.snapshots.remove(&guild_id),
+        };
+
+        true
+    }
+}
+
+pub async fn save(data: &RwLock<TypeMap>, guild_id: GuildId) {
+    let snapshot = snapshot(data, guild_id).await;
+
+    store(data, guild_id, snapshot).await;
+}
+
+pub async fn save_all(data: &RwLock<TypeMap>) {
+    let manager = data.read().await
+        .get::<SongbirdKey>()
+        .cloned()
+        .expect("Songbird Voice client placed in at initialisation.");
+
+    let guild_ids = manager.iter()
+        .map(|(guild_id, _)| GuildId::from(guild_id.0))
+        .collect::<Vec<_>>();
+
+    for guild_id in guild_ids {
+        save(data, guild_id).await;
+    }
+}
+
+pub async fn remove(data: &RwLock<TypeMap>, guild_id: GuildId) {
+    store(data, guild_id, None).await;
+}
+
+async fn store(data: &RwLock<TypeMap>, guild_id: GuildId, snapshot: Option<QueueSnapshot>) {
+    let writer = data.read().await
+        .get::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .writer
+        .clone();
+
+    let _writing = writer.lock().await;
+
+    let (path, snapshots) = {
+        let mut data = data.write().await;
+        let queue_snapshots = data.get_mut::<QueueSnapshots>()
+            .expect("Guaranteed to exist in the typemap.");
+
+        if !queue_snapshots.update(guild_id, snapshot) {
+            return;
+        }
+
+        (queue_snapshots.path.clone(), queue_snapshots.snapshots.clone())
+    };
+
+    if let Err(why) = tokio::task::spawn_blocking(move || save_json(&path, &snapshots)).await {
+        error!(%guild_id, error = ?why, "Failed to save queues");
+    }
+}
+
+async fn snapshot(data: &RwLock<TypeMap>, guild_id: GuildId) -> Option<QueueSnapshot> {
+    let manager = data.read().await
+        .get::<SongbirdKey>()
+        .cloned()
+        .expect("Songbird Voice client placed in at initialisation.");
+
+    let handler_lock = manager.get(guild_id)?;
+
+    let (voice_channel, queue) = {
+        let handler = handler_lock.lock().await;
+        (handler.current_channel()?, handler.queue().current_queue())
+    };
+
+    let position = match queue.first() {
+        Some(handle) => handle.get_info().await
+            .map(|state| state.position.as_secs())
+            .unwrap_or_default(),
+        None => return None,
+    };
+
+    let entries = queue.iter()
+        .filter_map(|handle| {
+            let data = handle.data::<TrackData>();
+            let metadata = &data.metadata;
+
+            let (source, local) = match (&metadata.webpage_url, &metadata.url) {
+                (Some(webpage_url), _) => (webpage_url.clone(), false),
+                (None, Some(path)) => (path.clone(), true),
+                (None, None) => return None,
+            };
+
+            Some(QueueEntry {
+                source,
+                local,
+                requester: data.requester.id,
+                requester_name: data.requester.name.clone(),
+                requester_avatar: data.requester.avatar.clone(),
+                requested_at: data.requested_at,
+                kind: data.source,
+            })
+        })
+        .collect();
+
+    let text_channel = data.read().await
+        .get::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .text_channel(guild_id);
+
+    Some(QueueSnapshot {
+        voice_channel: ChannelId::from(voice_channel.0),
+        text_channel,
+        position,
+        entries,
+    })
+}
+
+pub async fn is_restoring(data: &RwLock<TypeMap>, guild_id: GuildId) -> bool {
+    data.read().await
+        .get::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .restoring
+        .contains(&guild_id)
+}
+
+/// Restores saved queues in background tasks, only on the first call so reconnects don't restore them again.
+pub async fn restore_all(ctx: &Context) {
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let snapshots = {
+        let mut data = ctx.data.write().await;
+        let queue_snapshots = data.get_mut::<QueueSnapshots>()
+            .expect("Guaranteed to exist in the typemap.");
+
+        if queue_snapshots.restored {
+            return;
+        }
+        queue_snapshots.restored = true;
+
+        let snapshots = queue_snapshots.snapshots.clone().into_iter()
+            .filter(|(guild_id, _)| manager.get(*guild_id).is_none())
+            .collect::<Vec<_>>();
+
+        queue_snapshots.restoring.extend(snapshots.iter().map(|(guild_id, _)| *guild_id));
+
+        snapshots
+    };
+
+    for (guild_id, snapshot) in snapshots {
+        let ctx = ctx.clone();
+
+        tokio::spawn(async move {
+            restore(&ctx, guild_id, snapshot).await;
+        });
+    }
+}
+
+async fn restore(ctx: &Context, guild_id: GuildId, snapshot: QueueSnapshot) {
+    let text_channel = snapshot.text_channel;
+
+    let restored = restore_entries(ctx, guild_id, snapshot).await;
+
+    ctx.data.write().await
+        .get_mut::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .restoring
+        .remove(&guild_id);
+
+    if !restored {
+        return;
+    }
+
+    if let Some(channel_id) = text_channel {
+        ctx.data.write().await
+            .get_mut::<QueueSnapshots>()
+            .expect("Guaranteed to exist in the typemap.")
+            .set_text_channel(guild_id, channel_id);
+
+        let locale = guild_locale(&ctx.data, &ctx.cache, guild_id).await;
+
+        channel_message(&ctx.http, channel_id, Text::QueueRestored, &locale).await;
+    }
+
+    save(&ctx.data, guild_id).await;
+}
+
+async fn restore_entries(ctx: &Context, guild_id: GuildId, snapshot: QueueSnapshot) -> bool {
+    if join(ctx, guild_id, snapshot.voice_channel).await.is_err() {
+        error!(%guild_id, channel_id = %snapshot.voice_channel, "Failed to rejoin voice channel");
+        return false;
+    }
+
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let handler_lock = match manager.get(guild_id) {
+        Some(handler) => handler,
+        None => return false,
+    };
+
+    let volume = guild_volume(&ctx.data, guild_id).await;
+
+    for (index, entry) in snapshot.entries.into_iter().enumerate() {
+        let resolved = if entry.local {
+            let metadata = ctx.data.read().await
+                .get::<FileCache>()
+                .expect("Guaranteed to exist in the typemap.")
+                .values()
+                .find(|metadata| metadata.url.as_ref() == Some(&entry.source))
+                .cloned();
+
+            match metadata {
+                Some(metadata) => local_track(&metadata).await.map(|track| (track, metadata)),
+                None => Err(TrackError::NoSuchFile),
+            }
+        } else {
+            resolve_query(ctx, &entry.source).await
+        };
+
+        let (mut track, metadata) = match resolved {
+            Ok(resolved) => resolved,
+            Err(why) => {
+                warn!(%guild_id, source = %entry.source, error = %why, "Failed to restore queue entry");
+                continue;
+            }
+        };
+
+        let requester = Requester {
+            id: entry.requester,
+            name: entry.requester_name,
+            avatar: entry.requester_avatar,
+        };
+
+        let mut data = TrackData::new(metadata, requester, guild_id, entry.kind);
+        if entry.requested_at > 0 {
+            data.requested_at = entry.requested_at;
+        }
+
+        track.user_data = Arc::new(data);
+        track.volume = volume;
+
+        let handle = handler_lock.lock().await.enqueue(track).await;
+
+        if index == 0 && snapshot.position > 0
+            && let Err(why) = handle.seek(Duration::from_secs(snapshot.position)).result_async().await {
+            error!(%guild_id, error = ?why, "Failed to resume track position");
+        }
+    }
+
+    true
+}
@@ -0,0 +1,186 @@
+use serde_json::Value;
+
+use serenity::{
+    all::{
+        Command,
+        CreateCommand,
+        GuildId,
+    },
+    http::Http,
+};
+
+use crate::commands;
+
+/// Registers commands from `commands/` globally or in given guild. Registered commands are
+/// overwritten only when they differ, which also removes stale ones. Returns whether anything changed.
+pub async fn sync_commands(http: &Http, guild_id: Option<GuildId>) -> serenity::Result<bool> {
+    let desired = commands::register_all();
+
+    let registered = match guild_id {
+        Some(guild_id) => guild_id.get_commands_with_localizations(http).await?,
+        None => Command::get_global_commands_with_localizations(http).await?,
+    };
+
+    if up_to_date(&desired, &registered) {
+        return Ok(false);
+    }
+
+    match guild_id {
+        Some(guild_id) => guild_id.set_commands(http, desired).await?,
+        None => Command::set_global_commands(http, desired).await?,
+    };
+
+    Ok(true)
+}
+
+fn up_to_date(desired: &[CreateCommand], registered: &[Command]) -> bool {
+    let registered: Vec<Value> = registered.iter()
+        .filter_map(|command| serde_json::to_value(command).ok())
+        .collect();
+
+    desired.len() == registered.len() && desired.iter().all(|command| {
+        let Ok(command) = serde_json::to_value(command) else {
+            return false;
+        };
+
+        registered.iter().any(|registered| matches(&command, registered, SERVER_FIELDS))
+    })
+}
+
+// Fields Discord adds to registered commands, either ids or defaults of fields the builder leaves unset.
+const SERVER_FIELDS: &[&str] = &[
+    "id",
+    "application_id",
+    "version",
+    "type",
+    "guild_id",
+    "dm_permission",
+    "integration_types",
+    "handler",
+];
+
+// Discord omits empty and default fields, so missing ones are treated as empty. Fields set only on the
+// registered side must be empty too, e.g. removed permissions or localizations of a deleted locale.
+fn matches(desired: &Value, registered: &Value, ignored: &[&str]) -> bool {
+    match (desired, registered) {
+        (Value::Object(desired), Value::Object(registered)) => {
+            let desired_match = desired.iter().all(|(key, value)| {
+                match registered.get(key) {
+                    Some(registered) => matches(value, registered, &[]),
+                    None => is_empty(value),
+                }
+            });
+
+            desired_match && registered.iter()
+                .filter(|(key, _)| !desired.contains_key(*key) && !ignored.contains(&key.as_str()))
+                .all(|(_, value)| is_empty(value))
+        },
+        (Value::Array(desired), Value::Array(registered)) => {
+            desired.len() == registered.len()
+                && desired.iter().zip(registered).all(|(desired, registered)| matches(desired, registered, &[]))
+        }
+        // Discord returns whole numbers without a fraction, e.g. 0.0 comes back as 0.
+        (Value::Number(desired), Value::Number(registered)) => desired.as_f64() == registered.as_f64(),
+        (desired, Value::Null) => is_empty(desired),
+        (desired, registered) => desired == registered,
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => true,
+        Value::Array(values) => values.is_empty(),
+        Value::Object(values) => values.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use serenity::all::{
+        CommandOptionType,
+        CreateCommandOption,
+    };
+
+    use super::*;
+
+    fn desired() -> Vec<CreateCommand> {
+        vec![
+            CreateCommand::new("volume")
+                .description("Change volume")
+                .name_localized("pl", "glosnosc")
+                .add_option(
+                    CreateCommandOption::new(CommandOptionType::Number, "percent", "Volume in percent")
+                        .min_number_value(0.0)
+                        .max_number_value(200.0)
+                        .required(true)
+                ),
+        ]
+    }
+
+    /// The desired command as Discord returns it, with `changes` applied on top.
+    fn registered(changes: Value) -> Vec<Command> {
+        let mut command = json!({
+            "id": "1",
+            "application_id": "2",
+            "version": "3",
+            "type": 1,
+            "name": "volume",
+            "name_localizations": {
+                "pl": "glosnosc",
+            },
+            "description": "Change volume",
+            "description_localizations": null,
+            "default_member_permissions": null,
+            "dm_permission": true,
+            "nsfw": false,
+            "contexts": null,
+            "integration_types": [0],
+            "options": [
+                {
+                    "type": 10,
+                    "name": "percent",
+                    "description": "Volume in percent",
+                    "required": true,
+                    "min_value": 0,
+                    "max_value": 200,
+                },
+            ],
+        });
+
+        if let (Value::Object(command), Value::Object(changes)) = (&mut command, changes) {
+            command.extend(changes);
+        }
+
+        vec![serde_json::from_value(command).expect("Valid command fixture")]
+    }
+
+    #[test]
+    fn registered_commands_are_up_to_date() {
+        assert!(up_to_date(&desired(), &registered(json!({}))));
+    }
+
+    #[test]
+    fn changed_description_is_not_up_to_date() {
+        assert!(!up_to_date(&desired(), &registered(json!({ "description": "Set volume" }))));
+    }
+
+    #[test]
+    fn removed_permissions_are_not_up_to_date() {
+        assert!(!up_to_date(&desired(), &registered(json!({ "default_member_permissions": "32" }))));
+    }
+
+    #[test]
+    fn removed_localization_is_not_up_to_date() {
+        let name_localizations = json!({ "name_localizations": { "pl": "glosnosc", "de": "lautstaerke" } });
+
+        assert!(!up_to_date(&desired(), &registered(name_localizations)));
+    }
+
+    #[test]
+    fn missing_command_is_not_up_to_date() {
+        assert!(!up_to_date(&desired(), &[]));
+    }
+}
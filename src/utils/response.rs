@@ -7,16 +7,42 @@ use serenity::all::{
     CreateInteractionResponseFollowup,
     CreateEmbed,
     CreateEmbedAuthor,
+    CreateEmbedFooter,
+    CreateMessage,
+    ChannelId,
+    Http,
+    Mentionable,
 };
 
+use std::sync::Arc;
+
+use tracing::error;
+
 use super::{
-    audio::Metadata,
-    localization::Text,
+    audio::TrackData,
+    cli::{
+        Config,
+        IdleTimeout,
+    },
+    localization::{
+        locales,
+        Text,
+    },
+    formatting,
+    play_log::Statistics,
+    metrics::metrics,
+    settings::{
+        command_locale,
+        GuildSettings,
+    },
 };
 
+// Discord rejects embed field values longer than this.
+const FIELD_VALUE_LIMIT: usize = 1024;
+
 pub enum Message {
     Text(Text),
-    Embed(CreateEmbed),
+    Embed(Box<CreateEmbed>),
 }
 
 impl From<Text> for Message {
@@ -27,49 +53,79 @@ impl From<Text> for Message {
 
 impl From<CreateEmbed> for Message {
     fn from(embed: CreateEmbed) -> Self {
-        Message::Embed(embed)
+        Message::Embed(Box::new(embed))
     }
 }
 
 pub async fn normal_response(ctx: &Context, command: &CommandInteraction, message: Message) {
+    record_outcome(ctx, command, &message).await;
+
     let message = match message {
         Message::Text(text) => CreateInteractionResponseMessage::new()
-            .content(text.localization(&command.locale)),
+            .content(text.localization(&command_locale(&ctx.data, command).await)),
         Message::Embed(embed) => CreateInteractionResponseMessage::new()
-            .embed(embed),
+            .embed(*embed),
     };
 
     let builder = CreateInteractionResponse::Message(message);
 
     if let Err(why) = command.create_response(&ctx.http, builder).await {
-        eprintln!("Failed to create interaction response: {why:?}");
+        error!(error = ?why, "Failed to create interaction response");
     }
 }
 
 pub async fn edit_response(ctx: &Context, command: &CommandInteraction, message: Message) {
+    record_outcome(ctx, command, &message).await;
+
     let builder = match message {
         Message::Text(text) => EditInteractionResponse::new()
-            .content(text.localization(&command.locale)),
+            .content(text.localization(&command_locale(&ctx.data, command).await)),
         Message::Embed(embed) => EditInteractionResponse::new()
-            .embed(embed),
+            .embed(*embed),
     };
 
     if let Err(why) = command.edit_response(&ctx.http, builder).await {
-        eprintln!("Failed to edit interaction response: {why:?}");
+        error!(error = ?why, "Failed to edit interaction response");
     }
 }
 
+async fn record_outcome(ctx: &Context, command: &CommandInteraction, message: &Message) {
+    let outcome = match message {
+        Message::Text(text) => text.outcome(),
+        Message::Embed(_) => "ok",
+    };
+
+    metrics(&ctx.data).await.command(&command.data.name, outcome);
+}
+
 pub async fn followup_response(ctx: &Context, command: &CommandInteraction, embed: CreateEmbed) {
     let builder = CreateInteractionResponseFollowup::new()
         .embed(embed);
 
     if let Err(why) = command.create_followup(&ctx.http, builder).await {
-        eprintln!("Failed to create followup response: {why:?}");
+        error!(error = ?why, "Failed to create followup response");
     }
 
 }
 
-pub fn create_track_embed(metadata: &Metadata, queue_length: usize, is_now_playing: bool, locale: &String) -> CreateEmbed {
+pub async fn channel_message(http: &Http, channel_id: ChannelId, text: Text, locale: &str) {
+    if let Err(why) = channel_id.say(http, text.localization(locale)).await {
+        error!(error = ?why, "Failed to send channel message");
+    }
+}
+
+pub async fn channel_embed(http: &Http, channel_id: ChannelId, embed: CreateEmbed) {
+    let builder = CreateMessage::new()
+        .embed(embed);
+
+    if let Err(why) = channel_id.send_message(http, builder).await {
+        error!(error = ?why, "Failed to send channel message");
+    }
+}
+
+pub fn create_track_embed(data: &TrackData, queue_length: usize, position: Option<usize>, starts_in: Option<u64>, is_now_playing: bool, locale: &str) -> CreateEmbed {
+    let metadata = &data.metadata;
+
     let mut embed = CreateEmbed::new();
 
     if let Some(track) = &metadata.track {
@@ -96,22 +152,24 @@ pub fn create_track_embed(metadata: &Metadata, queue_length: usize, is_now_playi
     
     
     if let Some(duration) = metadata.duration {
-        let hours = duration / 3600;
-        let minutes = (duration % 3600) / 60;
-        let seconds = duration % 60;
+        embed = embed.field(Text::Duration.localization(locale), format_duration(duration.into()), true);
+    }
 
-        let string = if hours > 0 {
-                format!("{hours}:{minutes:02}:{seconds:02}")
-            } else {
-                format!("{minutes}:{seconds:02}")
-            };
+    match position {
+        Some(position) => embed = embed.field(Text::QueuePosition.localization(locale), position.to_string(), true),
+        None if queue_length > 0 => embed = embed.field(Text::QueueLength.localization(locale), formatting::tracks(queue_length, locale), true),
+        None => {},
+    }
 
-        embed = embed.field(Text::Duration.localization(locale), string, true);
+    if let Some(starts_in) = starts_in {
+        embed = embed.field(Text::Starts.localization(locale), formatting::relative_duration(starts_in, locale), true);
     }
 
-    if queue_length > 0 {
-        embed = embed.field(Text::QueueLength.localization(locale), queue_length.to_string(), true);
+    let mut footer = CreateEmbedFooter::new(format!("{} {}", Text::RequestedBy.localization(locale), data.requester.name));
+    if let Some(avatar) = &data.requester.avatar {
+        footer = footer.icon_url(avatar);
     }
+    embed = embed.footer(footer);
 
     if is_now_playing {
         embed = embed.author(CreateEmbedAuthor::new(Text::NowPlaying.localization(locale)));
@@ -122,20 +180,22 @@ pub fn create_track_embed(metadata: &Metadata, queue_length: usize, is_now_playi
     embed
 }
 
-pub fn create_queue_embed(queue: &[Metadata], locale: &String) -> CreateEmbed {
+pub fn create_queue_embed(queue: &[Arc<TrackData>], locale: &str) -> CreateEmbed {
     let mut embed = CreateEmbed::new().title(Text::Queue.localization(locale));
 
     let unknown_title = Text::UnknownTitle.localization(locale);
 
-    let titles = queue.iter().take(50).map(|m| 
-        if let Some(track) = &m.track {
+    let titles = queue.iter().take(50).map(|data| {
+        let m = &data.metadata;
+        let title = if let Some(track) = &m.track {
             track
         } else if let Some(title) = &m.title {
             title
         } else {
             &unknown_title
-        }
-    ).collect::<Vec<_>>();
+        };
+        format!("{} ({})", title, data.requester.name)
+    }).collect::<Vec<_>>();
 
     let chunk_titles = titles.chunks_exact(2);
 
@@ -153,5 +213,150 @@ pub fn create_queue_embed(queue: &[Metadata], locale: &String) -> CreateEmbed {
         embed = embed.field(format!("{}. {}", titles.len(), chunk_remainder[0]), "", false);
     }
 
+    let total_duration: u64 = queue.iter()
+        .filter_map(|data| data.metadata.duration.map(u64::from))
+        .sum();
+
+    embed = embed.footer(CreateEmbedFooter::new(format!(
+        "{}, {}",
+        formatting::tracks(queue.len(), locale),
+        formatting::duration(total_duration, locale),
+    )));
+
+    embed
+}
+
+pub fn create_stats_embed(statistics: &Statistics, locale: &str) -> CreateEmbed {
+    let mut embed = CreateEmbed::new().title(Text::Statistics.localization(locale));
+
+    if statistics.top_tracks.is_empty() {
+        return embed.description(Text::NoStatistics.localization(locale));
+    }
+
+    let unknown_title = Text::UnknownTitle.localization(locale);
+
+    let top_tracks = statistics.top_tracks.iter()
+        .enumerate()
+        .map(|(index, (title, count))| {
+            let title = if title.is_empty() { &unknown_title } else { title };
+            format!("{}. {} ({})", index + 1, title, formatting::plays(*count, locale))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let top_requesters = statistics.top_requesters.iter()
+        .enumerate()
+        .map(|(index, (user_id, count))| format!("{}. {} ({})", index + 1, user_id.mention(), formatting::plays(*count, locale)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    embed = embed
+        .field(Text::TopTracks.localization(locale), top_tracks, false)
+        .field(Text::TopRequesters.localization(locale), top_requesters, false)
+        .field(Text::TotalListeningTime.localization(locale), formatting::duration(statistics.total_listened, locale), false);
+
     embed
-}
\ No newline at end of file
+}
+
+pub fn create_settings_embed(settings: &GuildSettings, config: &Config, locale: &str) -> CreateEmbed {
+    let language = match &settings.language {
+        Some(language) => locales().languages().into_iter()
+            .find(|(locale, _)| locale == language)
+            .map(|(_, name)| name)
+            .unwrap_or_else(|| language.clone()),
+        None => Text::PerUser.localization(locale),
+    };
+
+    let dj_role = match settings.dj_role {
+        Some(role_id) => role_id.mention().to_string(),
+        None => Text::NotSet.localization(locale),
+    };
+
+    let announcement_channel = match settings.announcement_channel {
+        Some(channel_id) => channel_id.mention().to_string(),
+        None => Text::CommandChannel.localization(locale),
+    };
+
+    let idle_timeout = match settings.idle_timeout(config) {
+        IdleTimeout::Immediate => Text::Immediately.localization(locale),
+        IdleTimeout::After(delay) => formatting::duration(delay.as_secs(), locale),
+        IdleTimeout::Never => Text::Never.localization(locale),
+    };
+
+    let max_queue_length = match settings.max_queue_length {
+        Some(max) => formatting::tracks(max, locale),
+        None => Text::Unlimited.localization(locale),
+    };
+
+    let max_user_tracks = match settings.max_user_tracks {
+        Some(max) => formatting::tracks(max, locale),
+        None => Text::Unlimited.localization(locale),
+    };
+
+    let max_duration = match settings.max_duration {
+        Some(max) => formatting::duration(max.into(), locale),
+        None => Text::Unlimited.localization(locale),
+    };
+
+    let vote_skip_threshold = match settings.vote_skip_threshold(config) {
+        Some(threshold) => format!("{}%", (threshold * 100.0).round()),
+        None => Text::Off.localization(locale),
+    };
+
+    let switch = |enabled: bool| match enabled {
+        true => Text::On.localization(locale),
+        false => Text::Off.localization(locale),
+    };
+
+    let list = |values: &[String]| match values.is_empty() {
+        true => Text::NotSet.localization(locale),
+        false => truncate(values.join(", "), FIELD_VALUE_LIMIT),
+    };
+
+    CreateEmbed::new()
+        .title(Text::ServerSettings.localization(locale))
+        .field(Text::SettingVolume.localization(locale), format!("{}%", settings.volume(config)), true)
+        .field(Text::SettingLanguage.localization(locale), language, true)
+        .field(Text::SettingDjRole.localization(locale), dj_role, true)
+        .field(Text::SettingAnnouncementChannel.localization(locale), announcement_channel, true)
+        .field(Text::SettingAnnounceNowPlaying.localization(locale), switch(settings.announces_now_playing()), true)
+        .field(Text::SettingIdleTimeout.localization(locale), idle_timeout, true)
+        .field(Text::SettingVoteSkipThreshold.localization(locale), vote_skip_threshold, true)
+        .field(Text::SettingMaxQueueLength.localization(locale), max_queue_length, true)
+        .field(Text::SettingMaxUserTracks.localization(locale), max_user_tracks, true)
+        .field(Text::SettingMaxDuration.localization(locale), max_duration, true)
+        .field(Text::SettingFairQueue.localization(locale), switch(settings.fair_queue), true)
+        .field(Text::SettingRefuseLivestreams.localization(locale), switch(config.refuse_livestreams || settings.refuse_livestreams), true)
+        .field(Text::SettingRefuseAgeRestricted.localization(locale), switch(config.refuse_age_restricted || settings.refuse_age_restricted), true)
+        .field(Text::SettingAllowedDomains.localization(locale), list(&settings.allowed_domains), false)
+        .field(Text::SettingBlockedDomains.localization(locale), list(&settings.blocked_domains), false)
+        .field(Text::SettingBlockedKeywords.localization(locale), list(&settings.blocked_keywords), false)
+        .field(Text::SettingBlockedUploaders.localization(locale), list(&settings.blocked_uploaders), false)
+}
+
+fn truncate(mut text: String, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text;
+    }
+
+    let end = text.char_indices()
+        .nth(limit - 1)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+
+    text.truncate(end);
+    text.push('…');
+    text
+}
+
+pub fn format_duration(duration: u64) -> String {
+    let hours = duration / 3600;
+    let minutes = (duration % 3600) / 60;
+    let seconds = duration % 60;
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    collections::HashMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use serenity::{
+    cache::Cache,
+    model::{
+        application::CommandInteraction,
+        id::{
+            ChannelId,
+            GuildId,
+            RoleId,
+        },
+    },
+    prelude::*,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::{
+    cli::{
+        Config,
+        IdleTimeout,
+    },
+    localization,
+    storage::{
+        data_file,
+        load_json,
+        save_json,
+    },
+};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct GuildSettings {
+    pub dj_role: Option<RoleId>,
+    pub max_user_tracks: Option<usize>,
+    pub max_duration: Option<u32>,
+    pub max_queue_length: Option<usize>,
+    pub fair_queue: bool,
+    pub allowed_domains: Vec<String>,
+    pub blocked_domains: Vec<String>,
+    pub blocked_keywords: Vec<String>,
+    pub blocked_uploaders: Vec<String>,
+    pub refuse_livestreams: bool,
+    pub refuse_age_restricted: bool,
+    pub language: Option<String>,
+    pub volume: Option<u8>,
+    pub announcement_channel: Option<ChannelId>,
+    pub idle_timeout: Option<IdleTimeout>,
+    pub vote_skip_threshold: Option<f64>,
+    pub announce_now_playing: Option<bool>,
+}
+
+/// Guild defaults from the config file. Unlike stored settings, unknown keys are an error so typos get reported.
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct GuildDefaults {
+    dj_role: Option<RoleId>,
+    max_user_tracks: Option<usize>,
+    max_duration: Option<u32>,
+    max_queue_length: Option<usize>,
+    fair_queue: bool,
+    allowed_domains: Vec<String>,
+    blocked_domains: Vec<String>,
+    blocked_keywords: Vec<String>,
+    blocked_uploaders: Vec<String>,
+    refuse_livestreams: bool,
+    refuse_age_restricted: bool,
+    language: Option<String>,
+    volume: Option<u8>,
+    announcement_channel: Option<ChannelId>,
+    idle_timeout: Option<IdleTimeout>,
+    vote_skip_threshold: Option<f64>,
+    announce_now_playing: Option<bool>,
+}
+
+impl GuildDefaults {
+    /// Validates the defaults the same way as options and `/settings`. `max_duration` is given in minutes.
+    pub fn into_settings(self, locales_directory: Option<&Path>) -> Result<GuildSettings, String> {
+        let max_duration = self.max_duration
+            .map(|minutes| minutes.checked_mul(60).ok_or(format!("max_duration: {minutes} minutes is too long")))
+            .transpose()?;
+
+        if let Some(volume) = self.volume && volume > 200 {
+            return Err(format!("volume: {volume} is not between 0 and 200"));
+        }
+
+        if let Some(threshold) = self.vote_skip_threshold && !(0.0..=1.0).contains(&threshold) {
+            return Err(format!("vote_skip_threshold: {threshold} is not between 0 and 1"));
+        }
+
+        let language = match self.language {
+            Some(language) if language == "user" => None,
+            Some(language) if !localization::is_available(locales_directory, &language) => {
+                return Err(format!("language: '{language}' is not a loaded locale or 'user'"));
+            },
+            language => language,
+        };
+
+        Ok(GuildSettings {
+            dj_role: self.dj_role,
+            max_user_tracks: self.max_user_tracks,
+            max_duration,
+            max_queue_length: self.max_queue_length,
+            fair_queue: self.fair_queue,
+            allowed_domains: self.allowed_domains,
+            blocked_domains: self.blocked_domains,
+            blocked_keywords: self.blocked_keywords,
+            blocked_uploaders: self.blocked_uploaders,
+            refuse_livestreams: self.refuse_livestreams,
+            refuse_age_restricted: self.refuse_age_restricted,
+            language,
+            volume: self.volume,
+            announcement_channel: self.announcement_channel,
+            idle_timeout: self.idle_timeout,
+            vote_skip_threshold: self.vote_skip_threshold,
+            announce_now_playing: self.announce_now_playing,
+        })
+    }
+}
+
+impl GuildSettings {
+    /// Volume of new tracks in percent, the bot's default volume if not set.
+    pub fn volume(&self, config: &Config) -> u8 {
+        self.volume.unwrap_or(config.default_volume)
+    }
+
+    pub fn idle_timeout(&self, config: &Config) -> IdleTimeout {
+        self.idle_timeout.unwrap_or(config.idle_timeout)
+    }
+
+    /// Fraction of listeners needed to skip a track, none if anyone can skip. Set to 0 to turn voting off.
+    pub fn vote_skip_threshold(&self, config: &Config) -> Option<f64> {
+        self.vote_skip_threshold
+            .or(config.vote_skip_threshold)
+            .filter(|threshold| *threshold > 0.0)
+    }
+
+    pub fn announces_now_playing(&self) -> bool {
+        self.announce_now_playing.unwrap_or(true)
+    }
+}
+
+pub struct Settings {
+    guilds: HashMap<GuildId, GuildSettings>,
+    defaults: GuildSettings,
+    path: Option<PathBuf>,
+}
+
+impl TypeMapKey for Settings {
+    type Value = Settings;
+}
+
+impl Settings {
+    pub fn load(directory: &Option<PathBuf>, defaults: GuildSettings) -> Settings {
+        let path = data_file(directory, SETTINGS_FILE);
+        let guilds = load_json(&path);
+
+        Settings {
+            guilds,
+            defaults,
+            path,
+        }
+    }
+
+    pub fn get(&self, guild_id: GuildId) -> GuildSettings {
+        self.guilds.get(&guild_id).cloned().unwrap_or_else(|| self.defaults.clone())
+    }
+
+    pub fn update(&mut self, guild_id: GuildId, update: impl FnOnce(&mut GuildSettings)) {
+        update(self.guilds.entry(guild_id).or_insert_with(|| self.defaults.clone()));
+        save_json(&self.path, &self.guilds);
+    }
+
+    /// Settings that guilds start with, from `guild_defaults` in the config file.
+    pub fn defaults(&self) -> &GuildSettings {
+        &self.defaults
+    }
+
+    pub fn reset(&mut self, guild_id: GuildId) {
+        self.guilds.remove(&guild_id);
+        save_json(&self.path, &self.guilds);
+    }
+}
+
+pub async fn guild_settings(ctx: &Context, guild_id: GuildId) -> GuildSettings {
+    let data = ctx.data.read().await;
+    data.get::<Settings>()
+        .expect("Guaranteed to exist in the typemap.")
+        .get(guild_id)
+}
+
+/// Volume of new tracks in the guild as a fraction.
+pub async fn guild_volume(data: &RwLock<TypeMap>, guild_id: GuildId) -> f32 {
+    let data = data.read().await;
+
+    let config = data.get::<Config>()
+        .expect("Guaranteed to exist in the typemap.");
+
+    let settings = data.get::<Settings>()
+        .expect("Guaranteed to exist in the typemap.")
+        .get(guild_id);
+
+    f32::from(settings.volume(config)) / 100.0
+}
+
+/// Locale of replies to a command: the guild language if one is set, otherwise the user's.
+pub async fn command_locale(data: &RwLock<TypeMap>, command: &CommandInteraction) -> String {
+    let language = match command.guild_id {
+        Some(guild_id) => data.read().await
+            .get::<Settings>()
+            .expect("Guaranteed to exist in the typemap.")
+            .get(guild_id)
+            .language,
+        None => None,
+    };
+
+    language.unwrap_or_else(|| command.locale.clone())
+}
+
+/// Locale of messages seen by everyone: the guild language if one is set, otherwise the server's preferred locale.
+pub async fn guild_locale(data: &RwLock<TypeMap>, cache: &Cache, guild_id: GuildId) -> String {
+    let language = data.read().await
+        .get::<Settings>()
+        .expect("Guaranteed to exist in the typemap.")
+        .get(guild_id)
+        .language;
+
+    language
+        .or_else(|| cache.guild(guild_id).map(|guild| guild.preferred_locale.clone()))
+        .unwrap_or_default()
+}
@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use serenity::{
+    cache::Cache,
+    http::Http,
+    model::id::GuildId,
+    prelude::*,
+};
+
+use songbird::SongbirdKey;
+
+use tokio::sync::watch;
+
+use tracing::error;
+
+use super::{
+    localization::Text,
+    queue_state::{
+        self,
+        QueueSnapshots,
+    },
+    response::channel_message,
+    settings::guild_locale,
+};
+
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct Shutdown {
+    sender: watch::Sender<bool>,
+}
+
+impl TypeMapKey for Shutdown {
+    type Value = Shutdown;
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Shutdown {
+            sender: watch::Sender::new(false),
+        }
+    }
+}
+
+impl Shutdown {
+    pub fn begin(&self) {
+        self.sender.send_replace(true);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        *self.sender.borrow()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.sender.subscribe()
+    }
+}
+
+pub async fn is_shutting_down(data: &RwLock<TypeMap>) -> bool {
+    data.read().await
+        .get::<Shutdown>()
+        .expect("Guaranteed to exist in the typemap.")
+        .is_shutting_down()
+}
+
+pub async fn wait_for_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut sigterm = match signal(SignalKind::terminate()) {
+            Ok(sigterm) => sigterm,
+            Err(why) => {
+                error!(error = ?why, "Failed to listen for SIGTERM");
+                let _ = tokio::signal::ctrl_c().await;
+                return;
+            }
+        };
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {},
+            _ = sigterm.recv() => {},
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+pub async fn cleanup(http: &Http, cache: &Cache, data: &RwLock<TypeMap>) {
+    data.read().await
+        .get::<Shutdown>()
+        .expect("Guaranteed to exist in the typemap.")
+        .begin();
+
+    queue_state::save_all(data).await;
+
+    data.write().await
+        .get_mut::<QueueSnapshots>()
+        .expect("Guaranteed to exist in the typemap.")
+        .freeze();
+
+    let manager = data.read().await
+        .get::<SongbirdKey>()
+        .cloned()
+        .expect("Songbird Voice client placed in at initialisation.");
+
+    let guild_ids = manager.iter()
+        .map(|(guild_id, _)| GuildId::from(guild_id.0))
+        .collect::<Vec<_>>();
+
+    for guild_id in guild_ids {
+        let text_channel = data.read().await
+            .get::<QueueSnapshots>()
+            .expect("Guaranteed to exist in the typemap.")
+            .text_channel(guild_id);
+
+        if let Some(channel_id) = text_channel {
+            let locale = guild_locale(data, cache, guild_id).await;
+
+            channel_message(http, channel_id, Text::Restarting, &locale).await;
+        }
+
+        if let Err(why) = manager.remove(guild_id).await {
+            error!(%guild_id, error = ?why, "Failed to leave voice channel");
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use serenity::{
+    model::id::{
+        GuildId,
+        UserId,
+    },
+    prelude::TypeMapKey,
+};
+
+use uuid::Uuid;
+
+#[derive(Default)]
+pub struct SkipVotes {
+    votes: HashMap<GuildId, (Uuid, HashSet<UserId>)>,
+}
+
+impl TypeMapKey for SkipVotes {
+    type Value = SkipVotes;
+}
+
+impl SkipVotes {
+    pub fn vote(&mut self, guild_id: GuildId, track: Uuid, user_id: UserId, listeners: &[UserId]) -> usize {
+        let (voted_track, voters) = self.votes.entry(guild_id).or_default();
+
+        if *voted_track != track {
+            *voted_track = track;
+            voters.clear();
+        }
+
+        voters.insert(user_id);
+        voters.retain(|voter| listeners.contains(voter));
+        voters.len()
+    }
+
+    pub fn clear(&mut self, guild_id: GuildId) {
+        self.votes.remove(&guild_id);
+    }
+}
+
+pub fn required_votes(listeners: usize, threshold: f64) -> usize {
+    ((listeners as f64 * threshold).ceil() as usize).max(1)
+}
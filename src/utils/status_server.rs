@@ -0,0 +1,126 @@
+use std::{
+    io::{
+        Error,
+        ErrorKind,
+    },
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+
+use serenity::{
+    gateway::ShardManager,
+    prelude::*,
+};
+
+use songbird::Songbird;
+
+use tokio::{
+    io::{
+        AsyncReadExt,
+        AsyncWriteExt,
+    },
+    net::{
+        TcpListener,
+        TcpStream,
+    },
+    time::timeout,
+};
+
+use tracing::{
+    debug,
+    error,
+    info,
+};
+
+use super::{
+    health,
+    metrics::metrics,
+};
+
+// Clients that connect and send nothing are dropped after this.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy)]
+pub struct Endpoints {
+    pub metrics: bool,
+    pub health: bool,
+}
+
+#[derive(Clone)]
+pub struct ServerState {
+    pub data: Arc<RwLock<TypeMap>>,
+    pub manager: Arc<Songbird>,
+    pub shard_manager: Arc<ShardManager>,
+}
+
+pub async fn serve(addr: SocketAddr, endpoints: Endpoints, state: ServerState) {
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(why) => {
+            error!(%addr, error = ?why, "Failed to bind status server");
+            return;
+        }
+    };
+
+    info!(%addr, metrics = endpoints.metrics, health = endpoints.health, "Serving status endpoints");
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(why) => {
+                error!(error = ?why, "Failed to accept status connection");
+                continue;
+            }
+        };
+
+        let state = state.clone();
+
+        tokio::spawn(async move {
+            if let Err(why) = respond(stream, endpoints, &state).await {
+                debug!(error = ?why, "Failed to respond to status request");
+            }
+        });
+    }
+}
+
+async fn respond(mut stream: TcpStream, endpoints: Endpoints, state: &ServerState) -> std::io::Result<()> {
+    let mut buffer = [0; 1024];
+    let read = timeout(READ_TIMEOUT, stream.read(&mut buffer))
+        .await
+        .map_err(|_| Error::new(ErrorKind::TimedOut, "no request received"))??;
+
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+    let (status, content_type, body) = match path {
+        "/metrics" if endpoints.metrics => {
+            ("200 OK", "text/plain; version=0.0.4", metrics(&state.data).await.render(&state.data, &state.manager).await)
+        },
+        "/healthz" if endpoints.health => {
+            let (healthy, body) = health::liveness(&state.shard_manager).await;
+            (status_line(healthy), "application/json", body)
+        },
+        "/readyz" if endpoints.health => {
+            let (ready, body) = health::readiness(&state.data).await;
+            (status_line(ready), "application/json", body)
+        },
+        _ => ("404 Not Found", "text/plain", "Not found\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len(),
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+fn status_line(ok: bool) -> &'static str {
+    if ok {
+        "200 OK"
+    } else {
+        "503 Service Unavailable"
+    }
+}
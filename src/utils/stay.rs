@@ -0,0 +1,317 @@
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    fs::read_to_string,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use serenity::{
+    model::id::{
+        ChannelId,
+        GuildId,
+    },
+    prelude::*,
+};
+
+use songbird::{
+    input::{
+        File,
+        Input,
+    },
+    tracks::Track,
+};
+
+use rand::seq::SliceRandom;
+
+use tracing::{
+    error,
+    warn,
+};
+
+use super::{
+    audio::{
+        join,
+        FileCache,
+        Metadata,
+        Requester,
+        SourceKind,
+        TrackData,
+    },
+    cli::Config,
+    queue_state,
+    settings::guild_volume,
+    storage::{
+        data_file,
+        load_json,
+        save_json,
+    },
+};
+
+const STAY_FILE: &str = "stay.json";
+
+const REJOIN_DELAY: Duration = Duration::from_secs(5);
+
+// Consecutive fallback tracks that failed to play are retried after a growing delay, then given up on.
+const FALLBACK_RETRY_DELAY: Duration = Duration::from_secs(5);
+const MAX_FALLBACK_FAILURES: u32 = 5;
+
+pub struct StayChannels {
+    channels: HashMap<GuildId, ChannelId>,
+    fallback_queues: HashMap<GuildId, VecDeque<String>>,
+    fallback_failures: HashMap<GuildId, u32>,
+    path: Option<PathBuf>,
+}
+
+impl TypeMapKey for StayChannels {
+    type Value = StayChannels;
+}
+
+impl StayChannels {
+    pub fn load(directory: &Option<PathBuf>) -> StayChannels {
+        let path = data_file(directory, STAY_FILE);
+        let channels = load_json(&path);
+
+        StayChannels {
+            channels,
+            fallback_queues: HashMap::new(),
+            fallback_failures: HashMap::new(),
+            path,
+        }
+    }
+
+    pub fn get(&self, guild_id: GuildId) -> Option<ChannelId> {
+        self.channels.get(&guild_id).copied()
+    }
+
+    pub fn all(&self) -> Vec<(GuildId, ChannelId)> {
+        self.channels.iter().map(|(guild_id, channel_id)| (*guild_id, *channel_id)).collect()
+    }
+
+    pub fn insert(&mut self, guild_id: GuildId, channel_id: ChannelId) {
+        self.channels.insert(guild_id, channel_id);
+        self.fallback_failures.remove(&guild_id);
+        self.save();
+    }
+
+    /// Pins the bot to the channel it was moved to, does nothing if stay mode is off.
+    pub fn move_to(&mut self, guild_id: GuildId, channel_id: ChannelId) {
+        if let Some(pinned) = self.channels.get_mut(&guild_id) && *pinned != channel_id {
+            *pinned = channel_id;
+            self.save();
+        }
+    }
+
+    pub fn remove(&mut self, guild_id: GuildId) -> Option<ChannelId> {
+        let removed = self.channels.remove(&guild_id);
+        if removed.is_some() {
+            self.save();
+        }
+        removed
+    }
+
+    /// Name of the next fallback file of the current pass, none when the pass is over.
+    fn next_fallback(&mut self, guild_id: GuildId) -> Option<String> {
+        self.fallback_queues.get_mut(&guild_id)?.pop_front()
+    }
+
+    fn start_fallback_pass(&mut self, guild_id: GuildId, names: Vec<String>) {
+        self.fallback_queues.insert(guild_id, names.into());
+    }
+
+    pub fn fallback_ended(&mut self, guild_id: GuildId, failed: bool) {
+        if failed {
+            *self.fallback_failures.entry(guild_id).or_default() += 1;
+        } else {
+            self.fallback_failures.remove(&guild_id);
+        }
+    }
+
+    fn save(&self) {
+        save_json(&self.path, &self.channels);
+    }
+}
+
+pub async fn stay_channel(ctx: &Context, guild_id: GuildId) -> Option<ChannelId> {
+    let data = ctx.data.read().await;
+    data.get::<StayChannels>()
+        .expect("Guaranteed to exist in the typemap.")
+        .get(guild_id)
+}
+
+pub async fn rejoin_all(ctx: &Context) {
+    let channels = {
+        let data = ctx.data.read().await;
+        data.get::<StayChannels>()
+            .expect("Guaranteed to exist in the typemap.")
+            .all()
+    };
+
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    for (guild_id, channel_id) in channels {
+        if manager.get(guild_id).is_some() || queue_state::is_restoring(&ctx.data, guild_id).await {
+            continue;
+        }
+
+        if join(ctx, guild_id, channel_id).await.is_err() {
+            error!(%guild_id, %channel_id, "Failed to rejoin voice channel");
+            continue;
+        }
+
+        play_fallback(ctx, guild_id).await;
+    }
+}
+
+pub fn schedule_rejoin(ctx: &Context, guild_id: GuildId) {
+    let ctx = ctx.clone();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(REJOIN_DELAY).await;
+
+        let channel_id = match stay_channel(&ctx, guild_id).await {
+            Some(channel_id) => channel_id,
+            None => return,
+        };
+
+        if join(&ctx, guild_id, channel_id).await.is_err() {
+            error!(%guild_id, %channel_id, "Failed to rejoin voice channel");
+            return;
+        }
+
+        play_fallback(&ctx, guild_id).await;
+    });
+}
+
+/// Queues the next fallback track if the queue is empty. After failed fallback tracks it waits
+/// longer each time and stops trying after [`MAX_FALLBACK_FAILURES`] in a row.
+pub async fn play_fallback(ctx: &Context, guild_id: GuildId) {
+    let failures = ctx.data.read().await
+        .get::<StayChannels>()
+        .expect("Guaranteed to exist in the typemap.")
+        .fallback_failures
+        .get(&guild_id)
+        .copied()
+        .unwrap_or_default();
+
+    if failures >= MAX_FALLBACK_FAILURES {
+        warn!(%guild_id, failures, "Fallback tracks keep failing, not playing them until stay mode is enabled again");
+        return;
+    }
+
+    if failures == 0 {
+        return enqueue_fallback(ctx, guild_id).await;
+    }
+
+    let ctx = ctx.clone();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(FALLBACK_RETRY_DELAY * failures).await;
+
+        if stay_channel(&ctx, guild_id).await.is_some() {
+            enqueue_fallback(&ctx, guild_id).await;
+        }
+    });
+}
+
+async fn enqueue_fallback(ctx: &Context, guild_id: GuildId) {
+    let manager = songbird::get(ctx)
+        .await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let handler_lock = match manager.get(guild_id) {
+        Some(handler) => handler,
+        None => return,
+    };
+
+    if !handler_lock.lock().await.queue().is_empty() {
+        return;
+    }
+
+    let metadata = match fallback_entry(ctx, guild_id).await {
+        Some(metadata) => metadata,
+        None => return,
+    };
+
+    let path = match &metadata.url {
+        Some(path) => path.clone(),
+        None => return,
+    };
+
+    let requester = Requester::from_user(&ctx.cache.current_user());
+
+    let volume = guild_volume(&ctx.data, guild_id).await;
+
+    // One track at a time, the next one is queued when this one ends.
+    let mut track = Track::from(Input::from(File::new(path)));
+    track.user_data = Arc::new(TrackData::new(metadata, requester, guild_id, SourceKind::Fallback));
+    track.volume = volume;
+
+    handler_lock.lock().await.enqueue(track).await;
+}
+
+/// Next fallback track, going through the playlist in order or through the shuffled library, one pass at a time.
+async fn fallback_entry(ctx: &Context, guild_id: GuildId) -> Option<Metadata> {
+    let playlist = ctx.data.read().await
+        .get::<Config>()
+        .expect("Guaranteed to exist in the typemap.")
+        .fallback_playlist
+        .clone();
+
+    let playlist = playlist.and_then(|playlist| match read_to_string(&playlist) {
+        Ok(content) => Some(content),
+        Err(why) => {
+            error!(error = ?why, "Failed to read fallback playlist");
+            None
+        }
+    });
+
+    let mut data = ctx.data.write().await;
+    let mut started_pass = false;
+
+    loop {
+        let next = data.get_mut::<StayChannels>()
+            .expect("Guaranteed to exist in the typemap.")
+            .next_fallback(guild_id);
+
+        let name = match next {
+            Some(name) => name,
+            None if started_pass => return None,
+            None => {
+                let files = data.get::<FileCache>().expect("Guaranteed to exist in the typemap.");
+
+                let names = match &playlist {
+                    Some(content) => content.lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                    None => {
+                        let mut names = files.keys().cloned().collect::<Vec<_>>();
+                        names.shuffle(&mut rand::rng());
+                        names
+                    },
+                };
+
+                data.get_mut::<StayChannels>()
+                    .expect("Guaranteed to exist in the typemap.")
+                    .start_fallback_pass(guild_id, names);
+
+                started_pass = true;
+                continue;
+            },
+        };
+
+        // Files removed from the library since the pass started are skipped.
+        if let Some(metadata) = data.get::<FileCache>().expect("Guaranteed to exist in the typemap.").get(&name) {
+            return Some(metadata.clone());
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    fs::{
+        read_to_string,
+        write,
+    },
+    io::ErrorKind,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+use tracing::error;
+
+pub fn data_file(directory: &Option<PathBuf>, name: &str) -> Option<PathBuf> {
+    directory.as_ref().map(|dir| dir.join(name))
+}
+
+pub fn load_json<T: DeserializeOwned + Default>(path: &Option<PathBuf>) -> T {
+    let path = match path {
+        Some(path) => path,
+        None => return T::default(),
+    };
+
+    match read_to_string(path) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(value) => value,
+            Err(why) => {
+                error!(path = %path.display(), error = ?why, "Failed to parse data file");
+                T::default()
+            }
+        },
+        Err(why) if why.kind() == ErrorKind::NotFound => T::default(),
+        Err(why) => {
+            error!(path = %path.display(), error = ?why, "Failed to read data file");
+            T::default()
+        }
+    }
+}
+
+pub fn save_json<T: Serialize>(path: &Option<PathBuf>, value: &T) {
+    if let Some(path) = path {
+        let result = serde_json::to_string(value)
+            .map_err(|why| why.to_string())
+            .and_then(|content| write_atomically(path, content).map_err(|why| why.to_string()));
+
+        if let Err(why) = result {
+            error!(path = %path.display(), error = %why, "Failed to save data file");
+        }
+    }
+}
+
+fn write_atomically(path: &Path, content: String) -> std::io::Result<()> {
+    let temporary = path.with_extension("tmp");
+    write(&temporary, content)?;
+    std::fs::rename(temporary, path)
+}
@@ -0,0 +1,66 @@
+use std::{
+    env,
+    fmt,
+    fs::read_to_string,
+    io,
+    path::PathBuf,
+};
+
+use serenity::utils::validate_token;
+
+use super::cli::Config;
+
+const TOKEN_VARIABLE: &str = "DISCORD_TOKEN";
+
+const CREDENTIAL_NAME: &str = "discord-token";
+
+pub enum TokenError {
+    Missing,
+    Unreadable(PathBuf, io::Error),
+    Empty(String),
+    Malformed(String),
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::Missing => write!(
+                f,
+                "no Discord token given, set {TOKEN_VARIABLE}, pass --token or provide the '{CREDENTIAL_NAME}' systemd credential"
+            ),
+            TokenError::Unreadable(path, why) => write!(f, "can't read token from '{}': {why}", path.display()),
+            TokenError::Empty(source) => write!(f, "token from {source} is empty"),
+            TokenError::Malformed(source) => write!(f, "token from {source} doesn't look like a Discord bot token"),
+        }
+    }
+}
+
+pub fn load_token(config: &Config) -> Result<String, TokenError> {
+    let (token, source) = if let Some(path) = &config.token {
+        (read_token(path)?, format!("'{}'", path.display()))
+    } else if let Ok(token) = env::var(TOKEN_VARIABLE) {
+        (token, TOKEN_VARIABLE.to_string())
+    } else if let Some(directory) = env::var_os("CREDENTIALS_DIRECTORY") {
+        let path = PathBuf::from(directory).join(CREDENTIAL_NAME);
+        (read_token(&path)?, format!("credential '{CREDENTIAL_NAME}'"))
+    } else {
+        return Err(TokenError::Missing);
+    };
+
+    let token = token.trim();
+    let token = token.strip_prefix("Bot ").unwrap_or(token).trim();
+
+    if token.is_empty() {
+        return Err(TokenError::Empty(source));
+    }
+
+    if validate_token(token).is_err() {
+        return Err(TokenError::Malformed(source));
+    }
+
+    Ok(token.to_string())
+}
+
+fn read_token(path: &PathBuf) -> Result<String, TokenError> {
+    read_to_string(path).map_err(|why| TokenError::Unreadable(path.clone(), why))
+}
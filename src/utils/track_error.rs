@@ -0,0 +1,155 @@
+use std::fmt;
+
+use super::localization::Text;
+
+#[derive(Debug)]
+pub enum TrackError {
+    MissingQuery,
+    NoSuchFile,
+    YtDlpMissing,
+    YtDlp(YtDlpError),
+    Timeout,
+    ShuttingDown,
+    Parse(String),
+    StreamOpen(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum YtDlpError {
+    SignIn,
+    AgeGate,
+    RateLimited,
+    GeoRestricted,
+    NotFound,
+    Private,
+    UnsupportedUrl,
+    Extractor(String),
+    Other(String),
+}
+
+impl YtDlpError {
+    pub fn classify(stderr: &str) -> YtDlpError {
+        let lowercase = stderr.to_lowercase();
+        let contains = |patterns: &[&str]| patterns.iter().any(|pattern| lowercase.contains(pattern));
+
+        let message = stderr.lines()
+            .rfind(|line| line.starts_with("ERROR:"))
+            .or_else(|| stderr.lines().rfind(|line| !line.trim().is_empty()))
+            .unwrap_or_default()
+            .trim();
+
+        if contains(&["http error 429", "too many requests", "rate-limit", "rate limit"]) {
+            YtDlpError::RateLimited
+        } else if contains(&["confirm your age", "age-restricted", "age restricted", "inappropriate for some users"]) {
+            YtDlpError::AgeGate
+        } else if contains(&["private video", "video is private"]) {
+            // YouTube asks to sign in on private videos too, so these go first.
+            YtDlpError::Private
+        } else if contains(&["sign in", "login required", "log in", "--cookies", "authentication"]) {
+            YtDlpError::SignIn
+        } else if contains(&["not available in your country", "geo restrict", "geo-restrict", "geo-blocked"]) {
+            YtDlpError::GeoRestricted
+        } else if contains(&["unsupported url"]) {
+            YtDlpError::UnsupportedUrl
+        } else if contains(&["video unavailable", "http error 404", "not found", "does not exist", "is not available"]) {
+            YtDlpError::NotFound
+        } else if message.starts_with("ERROR: [") {
+            YtDlpError::Extractor(message.to_string())
+        } else {
+            YtDlpError::Other(message.to_string())
+        }
+    }
+
+    pub fn category(&self) -> &'static str {
+        match self {
+            YtDlpError::SignIn => "sign_in",
+            YtDlpError::AgeGate => "age_gate",
+            YtDlpError::RateLimited => "rate_limited",
+            YtDlpError::GeoRestricted => "geo_restricted",
+            YtDlpError::NotFound => "not_found",
+            YtDlpError::Private => "private",
+            YtDlpError::UnsupportedUrl => "unsupported_url",
+            YtDlpError::Extractor(_) => "extractor",
+            YtDlpError::Other(_) => "other",
+        }
+    }
+
+    pub fn needs_cookies(&self) -> bool {
+        matches!(self, YtDlpError::SignIn | YtDlpError::AgeGate | YtDlpError::Private)
+    }
+}
+
+impl fmt::Display for YtDlpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YtDlpError::SignIn => write!(f, "sign-in required"),
+            YtDlpError::AgeGate => write!(f, "age verification required"),
+            YtDlpError::RateLimited => write!(f, "rate limited"),
+            YtDlpError::GeoRestricted => write!(f, "geo-restricted"),
+            YtDlpError::NotFound => write!(f, "not found"),
+            YtDlpError::Private => write!(f, "private"),
+            YtDlpError::UnsupportedUrl => write!(f, "unsupported URL"),
+            YtDlpError::Extractor(message) => write!(f, "extractor error: {message}"),
+            YtDlpError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl fmt::Display for TrackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrackError::MissingQuery => write!(f, "no query given"),
+            TrackError::NoSuchFile => write!(f, "no such local file"),
+            TrackError::YtDlpMissing => write!(f, "yt-dlp executable not found"),
+            TrackError::YtDlp(error) => write!(f, "yt-dlp failed: {error}"),
+            TrackError::Timeout => write!(f, "yt-dlp timed out"),
+            TrackError::ShuttingDown => write!(f, "yt-dlp was killed due to shutdown"),
+            TrackError::Parse(why) => write!(f, "failed to parse metadata: {why}"),
+            TrackError::StreamOpen(why) => write!(f, "failed to open stream: {why}"),
+        }
+    }
+}
+
+impl From<TrackError> for Text {
+    fn from(error: TrackError) -> Self {
+        match error {
+            TrackError::MissingQuery => Text::UnknownCommand,
+            TrackError::NoSuchFile => Text::NoSuchFile,
+            TrackError::YtDlpMissing => Text::YtDlpMissing,
+            TrackError::YtDlp(error) => match error {
+                YtDlpError::SignIn => Text::LoginRequired,
+                YtDlpError::AgeGate => Text::AgeVerificationRequired,
+                YtDlpError::RateLimited => Text::RateLimited,
+                YtDlpError::GeoRestricted => Text::GeoBlocked,
+                YtDlpError::NotFound => Text::VideoUnavailable,
+                YtDlpError::Private => Text::PrivateVideo,
+                YtDlpError::UnsupportedUrl => Text::UnsupportedUrl,
+                YtDlpError::Extractor(_) => Text::ExtractorFailed,
+                YtDlpError::Other(_) => Text::FailedToFetch,
+            },
+            TrackError::Parse(_) => Text::FailedToFetch,
+            TrackError::Timeout => Text::FetchTimeout,
+            TrackError::ShuttingDown => Text::Restarting,
+            TrackError::StreamOpen(_) => Text::FailedToPlay,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_video_is_not_sign_in() {
+        let stderr = "ERROR: [youtube] dQw4w9WgXcQ: Private video. Sign in if you've been granted access to this video";
+
+        assert_eq!(YtDlpError::classify(stderr), YtDlpError::Private);
+    }
+
+    #[test]
+    fn sign_in_is_still_recognized() {
+        let stderr = "ERROR: [youtube] dQw4w9WgXcQ: Sign in to confirm you're not a bot. Use --cookies-from-browser or --cookies for the authentication.";
+
+        assert_eq!(YtDlpError::classify(stderr), YtDlpError::SignIn);
+    }
+}